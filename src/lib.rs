@@ -17,41 +17,300 @@
 use std::{
 	alloc,
 	alloc::Layout,
-	borrow::{Borrow, BorrowMut},
+	borrow::{Borrow, BorrowMut, Cow},
 	cell::Cell,
+	collections::HashSet,
+	convert::TryFrom,
 	fmt,
-	mem::{self, ManuallyDrop},
-	ops::{Deref, DerefMut},
+	hash::{Hash, Hasher},
+	io,
+	iter::{Product, Sum},
+	mem::{self, ManuallyDrop, MaybeUninit},
+	num::NonZeroUsize,
+	ops::{Deref, DerefMut, Range, RangeBounds},
+	pin::Pin,
 	ptr,
 	ptr::NonNull,
+	rc::Rc,
+	sync::Arc,
 };
 
 /// Fixed capacity array with immutable `push` method.
 pub struct ConstVec<T> {
 	ptr: NonNull<T>,
 	capacity: usize,
+
+	/// Layout of the allocation, computed once at construction so that `Drop`
+	/// never has to build a (fallible) layout.
+	///
+	/// Its alignment may be larger than the one of `T` (see
+	/// [`ConstVec::new_aligned`]), so it must always be used to reallocate or
+	/// deallocate the buffer instead of `Layout::array`.
+	layout: Layout,
+
 	len: Cell<usize>,
+
+	/// Incremented each time the vector is cleared, to detect stale
+	/// [`Handle`]s.
+	generation: Cell<u64>,
+
+	/// Length below which [`ConstVec::pop`] shrinks the buffer, or `0` if
+	/// the vector never shrinks automatically.
+	///
+	/// See [`ConstVec::new_auto_shrink`].
+	shrink_threshold: usize,
 }
 
 impl<T> ConstVec<T> {
 	/// Creates a new array with the given fixed capacity.
+	///
+	/// # Panics
+	///
+	/// Panics if the allocated size in bytes exceeds `isize::MAX`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(3);
+	/// vec.push(String::from("a"));
+	/// vec.push(String::from("b"));
+	///
+	/// assert_eq!(vec.capacity(), 3);
+	/// drop(vec);
+	/// ```
+	#[inline]
 	pub fn new(capacity: usize) -> ConstVec<T> {
-		let ptr = if capacity == 0 {
-			NonNull::dangling()
+		Self::with_capacity(capacity)
+	}
+
+	/// Creates a new array with the given fixed capacity.
+	///
+	/// This is the same as [`new`](ConstVec::new), named after
+	/// [`Vec::with_capacity`].
+	///
+	/// # Panics
+	///
+	/// Panics if the allocated size in bytes exceeds `isize::MAX`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::with_capacity(3);
+	/// vec.push(1);
+	///
+	/// let other = ConstVec::new(3);
+	/// other.push(1);
+	///
+	/// assert_eq!(vec, other);
+	/// assert_eq!(vec.capacity(), other.capacity());
+	/// ```
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> ConstVec<T> {
+		Self::try_new(capacity).unwrap_or_else(TryReserveError::handle)
+	}
+
+	/// Creates a new array with the given fixed capacity, returning an error
+	/// instead of panicking or aborting if the allocation fails.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::{ConstVec, TryReserveError};
+	/// let vec = ConstVec::<u32>::try_new(3).unwrap();
+	/// assert_eq!(vec.capacity(), 3);
+	///
+	/// assert_eq!(
+	///     ConstVec::<u32>::try_new(usize::MAX).unwrap_err(),
+	///     TryReserveError::CapacityOverflow
+	/// );
+	/// ```
+	pub fn try_new(capacity: usize) -> Result<ConstVec<T>, TryReserveError> {
+		let layout = Layout::array::<T>(capacity).map_err(|_| TryReserveError::CapacityOverflow)?;
+		Self::try_with_layout(capacity, layout)
+	}
+
+	/// Creates a new array with the given non-zero fixed capacity.
+	///
+	/// Unless `T` is zero-sized, this guarantees that the vector is backed by
+	/// an actual allocation.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// # use std::num::NonZeroUsize;
+	/// # use std::ptr::NonNull;
+	/// let vec = ConstVec::<u32>::with_nonzero_capacity(NonZeroUsize::new(4).unwrap());
+	/// assert_eq!(vec.capacity(), 4);
+	/// assert_ne!(vec.as_ptr(), NonNull::dangling().as_ptr());
+	/// ```
+	#[inline]
+	pub fn with_nonzero_capacity(capacity: NonZeroUsize) -> ConstVec<T> {
+		Self::new(capacity.get())
+	}
+
+	/// Creates a new array with the given capacity, whose buffer
+	/// automatically shrinks when elements are popped.
+	///
+	/// Whenever [`pop`](ConstVec::pop) makes the length fall below
+	/// `threshold`, the buffer is reallocated to a capacity of `threshold`,
+	/// if it was larger. This bounds the memory kept by long-lived stacks
+	/// after a peak, at the cost of one reallocation per peak.
+	///
+	/// A `threshold` of `0` disables automatic shrinking, as with
+	/// [`new`](ConstVec::new).
+	///
+	/// The threshold is kept by clones of the vector, by the vectors
+	/// returned by the mapping methods such as
+	/// [`map_into`](ConstVec::map_into), and by
+	/// [`IntoIter::into_const_vec`].
+	///
+	/// # Panics
+	///
+	/// Panics if the allocated size in bytes exceeds `isize::MAX`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new_auto_shrink(100, 10);
+	/// for i in 0..20 {
+	///     vec.push(i);
+	/// }
+	///
+	/// while vec.len() > 10 {
+	///     vec.pop();
+	/// }
+	/// assert_eq!(vec.capacity(), 100);
+	///
+	/// assert_eq!(vec.pop(), Some(9));
+	/// assert_eq!(vec.capacity(), 10);
+	/// assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+	/// ```
+	pub fn new_auto_shrink(capacity: usize, threshold: usize) -> ConstVec<T> {
+		let mut vec = Self::new(capacity);
+		vec.shrink_threshold = threshold;
+		vec
+	}
+
+	/// Returns the number of elements of type `T` that fit in the given
+	/// byte budget.
+	///
+	/// This helps choosing the capacity of a vector from a memory budget.
+	/// The budget is capped to `isize::MAX` bytes, so that the returned
+	/// capacity can always be passed to [`new`](ConstVec::new). Zero-sized
+	/// types never use any memory, so `usize::MAX` is returned for them.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// assert_eq!(ConstVec::<u64>::recommended_capacity_for_bytes(100), 12);
+	/// assert_eq!(ConstVec::<u64>::recommended_capacity_for_bytes(4), 0);
+	/// assert_eq!(ConstVec::<()>::recommended_capacity_for_bytes(0), usize::MAX);
+	///
+	/// let capacity = ConstVec::<[u8; 3]>::recommended_capacity_for_bytes(64);
+	/// let vec = ConstVec::<[u8; 3]>::new(capacity);
+	/// assert_eq!(vec.alloc_info().bytes_allocated, 63);
+	/// ```
+	#[inline]
+	pub fn recommended_capacity_for_bytes(byte_budget: usize) -> usize {
+		match mem::size_of::<T>() {
+			0 => usize::MAX,
+			size => byte_budget.min(isize::MAX as usize) / size,
+		}
+	}
+
+	/// Creates a new array with the given fixed capacity, whose buffer is
+	/// aligned to `align` bytes.
+	///
+	/// This is useful to get buffers suitable for SIMD instructions,
+	/// regardless of the natural alignment of `T`. The alignment is kept
+	/// when the buffer is reallocated.
+	///
+	/// # Panics
+	///
+	/// Panics if `align` is not a power of two, if it is smaller than the
+	/// alignment of `T`, or if the allocated size in bytes exceeds
+	/// `isize::MAX`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// for align in [16, 32, 64, 4096] {
+	///     let vec = ConstVec::<f32>::new_aligned(8, align);
+	///     assert_eq!(vec.as_ptr() as usize % align, 0);
+	///     assert_eq!(vec.capacity(), 8);
+	/// }
+	/// ```
+	///
+	/// The buffer is always deallocated with the layout it was allocated
+	/// with, whether it is dropped directly, through a partially consumed
+	/// [`IntoIter`], or after being reallocated:
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::<String>::new_aligned(4, 64);
+	/// vec.push(String::from("a"));
+	/// vec.push(String::from("b"));
+	/// drop(vec);
+	///
+	/// let vec = ConstVec::<String>::new_aligned(4, 64);
+	/// vec.push(String::from("a"));
+	/// vec.push(String::from("b"));
+	/// let mut iter = vec.into_iter();
+	/// assert_eq!(iter.next().as_deref(), Some("a"));
+	/// drop(iter);
+	///
+	/// let mut vec = ConstVec::<u8>::new_aligned(4, 64);
+	/// vec.push(1);
+	/// vec.grow_to(100);
+	/// assert_eq!(vec.as_ptr() as usize % 64, 0);
+	/// vec.shrink_to_fit();
+	/// assert_eq!(vec.as_ptr() as usize % 64, 0);
+	///
+	/// let converted: Vec<u8> = vec.into();
+	/// assert_eq!(converted, [1]);
+	/// ```
+	pub fn new_aligned(capacity: usize, align: usize) -> ConstVec<T> {
+		assert!(
+			align >= mem::align_of::<T>(),
+			"alignment must be at least the alignment of `T`"
+		);
+
+		let layout = Layout::array::<T>(capacity)
+			.map_err(|_| TryReserveError::CapacityOverflow)
+			.unwrap_or_else(TryReserveError::handle)
+			.align_to(align)
+			.expect("alignment must be a power of two");
+
+		Self::try_with_layout(capacity, layout).unwrap_or_else(TryReserveError::handle)
+	}
+
+	/// Creates a new array with the given fixed capacity, allocated with the
+	/// given layout.
+	///
+	/// `layout` must have the size of `capacity` elements of type `T`, and an
+	/// alignment at least as large as the one of `T`.
+	fn try_with_layout(capacity: usize, layout: Layout) -> Result<ConstVec<T>, TryReserveError> {
+		let ptr = if layout.size() == 0 {
+			dangling(layout)
 		} else {
-			let layout = Layout::array::<T>(capacity).unwrap();
 			let ptr = unsafe { alloc::alloc(layout) };
-			match NonNull::new(ptr as *mut T) {
-				Some(ptr) => ptr,
-				None => alloc::handle_alloc_error(layout),
-			}
+			NonNull::new(ptr as *mut T).ok_or(TryReserveError::AllocError { layout })?
 		};
 
-		ConstVec {
+		Ok(ConstVec {
 			ptr,
 			capacity,
+			layout,
 			len: Cell::new(0),
-		}
+			generation: Cell::new(0),
+			shrink_threshold: 0,
+		})
 	}
 
 	/// Creates a `ConstVec<T>` directly from a pointer, a capacity, and a
@@ -85,13 +344,22 @@ impl<T> ConstVec<T> {
 	/// that nothing else uses the pointer after calling this
 	/// function.
 	///
+	/// # Panics
+	///
+	/// Panics if the size of `T` times `capacity` overflows `isize::MAX`.
+	/// Such a capacity can never come from a valid allocation, and this check
+	/// is performed here so that dropping the vector never panics.
+	///
 	/// [`dealloc`]: alloc::dealloc
 	#[inline]
 	pub unsafe fn from_raw_parts(ptr: *mut T, len: usize, capacity: usize) -> Self {
 		Self {
 			ptr: NonNull::new_unchecked(ptr),
 			len: Cell::new(len),
+			generation: Cell::new(0),
 			capacity,
+			layout: Layout::array::<T>(capacity).expect("invalid capacity"),
+			shrink_threshold: 0,
 		}
 	}
 
@@ -108,7 +376,18 @@ impl<T> ConstVec<T> {
 	/// into a `ConstVec` with the [`from_raw_parts`] function, allowing
 	/// the destructor to perform the cleanup.
 	///
+	/// If the buffer is more aligned than `T`, the memory must instead be
+	/// deallocated by the caller using a layout with the same alignment,
+	/// since [`from_raw_parts`] assumes the alignment of `T`. This is the
+	/// case for vectors created with [`new_aligned`], and for vectors
+	/// returned by [`map_in_place`] when the original element type was more
+	/// aligned than the new one (e.g. from `u32` to `[u8; 4]`). The actual
+	/// alignment is given by [`alloc_info`].
+	///
 	/// [`from_raw_parts`]: ConstVec::from_raw_parts
+	/// [`new_aligned`]: ConstVec::new_aligned
+	/// [`map_in_place`]: ConstVec::map_in_place
+	/// [`alloc_info`]: ConstVec::alloc_info
 	///
 	/// # Examples
 	///
@@ -141,6 +420,13 @@ impl<T> ConstVec<T> {
 		self.capacity
 	}
 
+	/// Returns the number of elements that can still be pushed into the
+	/// vector.
+	#[inline]
+	pub fn remaining_capacity(&self) -> usize {
+		self.capacity - self.len()
+	}
+
 	#[inline]
 	pub fn len(&self) -> usize {
 		self.len.get()
@@ -151,6 +437,60 @@ impl<T> ConstVec<T> {
 		self.len() == 0
 	}
 
+	/// Returns `true` if the vector has no capacity left.
+	#[inline]
+	pub fn is_full(&self) -> bool {
+		self.len() == self.capacity()
+	}
+
+	/// Returns `true` if at least one more element can be pushed into the
+	/// vector.
+	///
+	/// This is the complement of [`is_full`](ConstVec::is_full).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(1);
+	/// assert!(vec.can_push());
+	///
+	/// vec.push(1);
+	/// assert!(!vec.can_push());
+	/// assert!(vec.is_full());
+	/// ```
+	#[inline]
+	pub fn can_push(&self) -> bool {
+		self.len() < self.capacity()
+	}
+
+	/// Returns a summary of the vector's allocation, for memory accounting.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec: ConstVec<u64> = ConstVec::new(4);
+	/// vec.push(1);
+	///
+	/// let info = vec.alloc_info();
+	/// assert_eq!(info.capacity, 4);
+	/// assert_eq!(info.len, 1);
+	/// assert_eq!(info.element_size, 8);
+	/// assert_eq!(info.alignment, std::mem::align_of::<u64>());
+	/// assert_eq!(info.bytes_allocated, 32);
+	/// ```
+	#[inline]
+	pub fn alloc_info(&self) -> AllocInfo {
+		AllocInfo {
+			capacity: self.capacity(),
+			len: self.len(),
+			element_size: mem::size_of::<T>(),
+			alignment: self.layout.align(),
+			bytes_allocated: self.layout.size(),
+		}
+	}
+
 	#[inline]
 	pub fn as_ptr(&self) -> *const T {
 		self.ptr.as_ptr()
@@ -171,6 +511,173 @@ impl<T> ConstVec<T> {
 		unsafe { std::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len()) }
 	}
 
+	/// Splits the vector into a slice of `N`-element arrays, starting at the
+	/// beginning, and a remainder slice with length strictly less than `N`.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(5);
+	/// vec.push('l');
+	/// vec.push('o');
+	/// vec.push('r');
+	/// vec.push('e');
+	/// vec.push('m');
+	///
+	/// let (chunks, remainder) = vec.as_chunks::<2>();
+	/// assert_eq!(chunks, &[['l', 'o'], ['r', 'e']]);
+	/// assert_eq!(remainder, &['m']);
+	///
+	/// let (chunks, remainder) = vec.as_chunks::<5>();
+	/// assert_eq!(chunks, &[['l', 'o', 'r', 'e', 'm']]);
+	/// assert!(remainder.is_empty());
+	/// ```
+	#[inline]
+	pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+		assert!(N != 0, "chunk size must be non-zero");
+		let chunk_count = self.len() / N;
+		let (multiple, remainder) = self.as_slice().split_at(chunk_count * N);
+		// SAFETY: `multiple` holds exactly `chunk_count * N` elements, and
+		// `[T; N]` has the same alignment as `T`.
+		let chunks =
+			unsafe { std::slice::from_raw_parts(multiple.as_ptr() as *const [T; N], chunk_count) };
+		(chunks, remainder)
+	}
+
+	/// Splits the vector into a slice of `N`-element arrays, starting at the
+	/// end, and a remainder slice with length strictly less than `N`.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(5);
+	/// vec.push('l');
+	/// vec.push('o');
+	/// vec.push('r');
+	/// vec.push('e');
+	/// vec.push('m');
+	///
+	/// let (remainder, chunks) = vec.as_rchunks::<2>();
+	/// assert_eq!(remainder, &['l']);
+	/// assert_eq!(chunks, &[['o', 'r'], ['e', 'm']]);
+	///
+	/// let (remainder, chunks) = vec.as_rchunks::<5>();
+	/// assert!(remainder.is_empty());
+	/// assert_eq!(chunks, &[['l', 'o', 'r', 'e', 'm']]);
+	/// ```
+	#[inline]
+	pub fn as_rchunks<const N: usize>(&self) -> (&[T], &[[T; N]]) {
+		assert!(N != 0, "chunk size must be non-zero");
+		let chunk_count = self.len() / N;
+		let (remainder, multiple) = self.as_slice().split_at(self.len() - chunk_count * N);
+		// SAFETY: `multiple` holds exactly `chunk_count * N` elements, and
+		// `[T; N]` has the same alignment as `T`.
+		let chunks =
+			unsafe { std::slice::from_raw_parts(multiple.as_ptr() as *const [T; N], chunk_count) };
+		(remainder, chunks)
+	}
+
+	/// Returns the size in bytes of the initialized elements of the vector.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::<u32>::new(4);
+	/// vec.push(1);
+	/// vec.push(2);
+	///
+	/// assert_eq!(vec.byte_len(), 8);
+	/// ```
+	#[inline]
+	pub fn byte_len(&self) -> usize {
+		mem::size_of_val(self.as_slice())
+	}
+
+	/// Returns the initialized elements of the vector as bytes, in native
+	/// endianness.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::<u32>::new(4);
+	/// vec.push(0x01020304);
+	/// vec.push(0x05060708);
+	///
+	/// let bytes = vec.as_bytes();
+	/// assert_eq!(bytes.len(), vec.byte_len());
+	/// assert_eq!(bytes[..4], 0x01020304u32.to_ne_bytes());
+	/// assert_eq!(bytes[4..], 0x05060708u32.to_ne_bytes());
+	///
+	/// let read = ConstVec::<u32>::from_pod_bytes(bytes);
+	/// assert_eq!(read, vec);
+	/// assert_eq!(read.capacity(), 2);
+	/// ```
+	#[cfg(feature = "bytemuck")]
+	#[inline]
+	pub fn as_bytes(&self) -> &[u8]
+	where
+		T: bytemuck::Pod,
+	{
+		bytemuck::cast_slice(self.as_slice())
+	}
+
+	/// Creates a new vector by copying the elements encoded in `bytes`, in
+	/// native endianness, with a capacity equal to their number.
+	///
+	/// `bytes` does not need to be aligned for `T`.
+	///
+	/// # Panics
+	///
+	/// Panics if the length of `bytes` is not a multiple of the size of `T`.
+	///
+	/// # Examples
+	///
+	/// ```should_panic
+	/// # use const_vec::ConstVec;
+	/// ConstVec::<u32>::from_pod_bytes(&[0; 6]);
+	/// ```
+	#[cfg(feature = "bytemuck")]
+	pub fn from_pod_bytes(bytes: &[u8]) -> ConstVec<T>
+	where
+		T: bytemuck::Pod,
+	{
+		let len = bytes.len().checked_div(mem::size_of::<T>()).unwrap_or(0);
+		assert_eq!(
+			len * mem::size_of::<T>(),
+			bytes.len(),
+			"byte length must be a multiple of the element size"
+		);
+
+		let result = Self::new(len);
+
+		// SAFETY: the buffer has room for `bytes`, and any bit pattern is a
+		// valid `T`.
+		unsafe {
+			ptr::copy_nonoverlapping(bytes.as_ptr(), result.ptr.as_ptr() as *mut u8, bytes.len());
+			result.len.set(len);
+		}
+
+		result
+	}
+
+	/// Appends an element to the back of the vector, through a shared
+	/// reference.
+	///
+	/// # Panics
+	///
+	/// Panics if the vector is full.
 	#[inline]
 	pub fn push(&self, value: T) {
 		if self.len() < self.capacity() {
@@ -180,6 +687,8 @@ impl<T> ConstVec<T> {
 				std::ptr::write(end, value);
 				self.len.set(len + 1);
 			}
+		} else if self.capacity() == 0 {
+			panic!("cannot push into a ConstVec with capacity 0")
 		} else {
 			panic!("not enough capacity")
 		}
@@ -205,10 +714,17 @@ impl<T> ConstVec<T> {
 		if self.len.get() == 0 {
 			None
 		} else {
-			unsafe {
+			let value = unsafe {
 				self.len.set(self.len.get() - 1);
-				Some(ptr::read(self.as_ptr().add(self.len())))
+				ptr::read(self.as_ptr().add(self.len()))
+			};
+			self.invalidate_handles();
+
+			if self.len() < self.shrink_threshold {
+				self.shrink_to(self.shrink_threshold)
 			}
+
+			Some(value)
 		}
 	}
 
@@ -244,52 +760,3236 @@ impl<T> ConstVec<T> {
 		}
 	}
 
-	/// Appends elements to `self` from other buffer.
+	/// Moves all the elements of another `ConstVec` into `self`, leaving
+	/// `other` empty.
 	///
-	/// The sum of the current length and length of `other` must not exceed
-	/// the capacity.
-	#[cfg(not(no_global_oom_handling))]
-	#[inline]
-	unsafe fn append_elements(&self, other: *const [T]) {
-		let count = unsafe { (*other).len() };
-		let len = self.len();
-		unsafe { ptr::copy_nonoverlapping(other as *const T, self.ptr.as_ptr().add(len), count) };
-		self.len.set(len + count);
-	}
-
-	/// Clears the vector, removing all values.
+	/// The capacity of `other` is unchanged.
 	///
-	/// Note that this method has no effect on the allocated capacity
-	/// of the vector.
+	/// # Panics
+	///
+	/// Panics if the current length and `other` length exceed the capacity.
 	///
 	/// # Examples
 	///
 	/// ```
 	/// # use const_vec::ConstVec;
-	/// let mut vec = ConstVec::new(3);
+	/// let vec = ConstVec::new(5);
 	/// vec.push(1);
 	/// vec.push(2);
-	/// vec.push(3);
 	///
-	/// vec.clear();
+	/// let mut other = ConstVec::from(vec![3, 4, 5]);
+	/// vec.append_const_vec(&mut other);
 	///
-	/// assert!(vec.is_empty());
+	/// assert_eq!(vec, [1, 2, 3, 4, 5]);
+	/// assert!(other.is_empty());
+	/// assert_eq!(other.capacity(), 3);
 	/// ```
-	#[inline]
-	pub fn clear(&mut self) {
-		let elems: *mut [T] = self.as_mut_slice();
-
-		// SAFETY:
-		// - `elems` comes directly from `as_mut_slice` and is therefore valid.
-		// - Setting `self.len` before calling `drop_in_place` means that,
-		//   if an element's `Drop` impl panics, the vector's `Drop` impl will
-		//   do nothing (leaking the rest of the elements) instead of dropping
-		//   some twice.
-		unsafe {
-			self.len.set(0);
-			ptr::drop_in_place(elems);
+	pub fn append_const_vec(&self, other: &mut ConstVec<T>) {
+		if self.len() + other.len() <= self.capacity() {
+			unsafe {
+				self.append_elements(other.as_slice() as _);
+				other.len.set(0);
+				other.invalidate_handles()
+			}
+		} else {
+			panic!("not enough capacity")
 		}
 	}
+
+	/// Moves as many leading elements of `other` as fit in the remaining
+	/// capacity of `self`, and returns the number of moved elements.
+	///
+	/// The elements left in `other` are shifted to its front.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(4);
+	/// vec.push(1);
+	///
+	/// let mut other = ConstVec::from(vec![2, 3]);
+	/// assert_eq!(vec.append_const_vec_partial(&mut other), 2);
+	/// assert_eq!(vec, [1, 2, 3]);
+	/// assert!(other.is_empty());
+	///
+	/// let mut other = ConstVec::from(vec![4, 5, 6]);
+	/// assert_eq!(vec.append_const_vec_partial(&mut other), 1);
+	/// assert_eq!(vec, [1, 2, 3, 4]);
+	/// assert_eq!(other, [5, 6]);
+	///
+	/// assert_eq!(vec.append_const_vec_partial(&mut other), 0);
+	/// assert_eq!(vec, [1, 2, 3, 4]);
+	/// assert_eq!(other, [5, 6]);
+	/// ```
+	pub fn append_const_vec_partial(&self, other: &mut ConstVec<T>) -> usize {
+		let other_len = other.len();
+		let count = self.remaining_capacity().min(other_len);
+
+		// SAFETY: `self` has room for `count` more elements, which are moved
+		// out of `other` before its remaining elements are shifted to the
+		// front.
+		unsafe {
+			self.append_elements(&other.as_slice()[..count] as _);
+			let ptr = other.as_mut_ptr();
+			ptr::copy(ptr.add(count), ptr, other_len - count);
+			other.len.set(other_len - count);
+		}
+
+		if count > 0 {
+			other.invalidate_handles()
+		}
+
+		count
+	}
+
+	/// Appends elements to `self` from other buffer.
+	///
+	/// The sum of the current length and length of `other` must not exceed
+	/// the capacity.
+	#[inline]
+	unsafe fn append_elements(&self, other: *const [T]) {
+		let count = unsafe { (&*other).len() };
+		let len = self.len();
+		unsafe { ptr::copy_nonoverlapping(other as *const T, self.ptr.as_ptr().add(len), count) };
+		self.len.set(len + count);
+	}
+
+	/// Clears the vector, removing all values.
+	///
+	/// Note that this method has no effect on the allocated capacity
+	/// of the vector.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(3);
+	/// vec.push(1);
+	/// vec.push(2);
+	/// vec.push(3);
+	///
+	/// vec.clear();
+	///
+	/// assert!(vec.is_empty());
+	/// ```
+	#[inline]
+	pub fn clear(&mut self) {
+		let elems: *mut [T] = self.as_mut_slice();
+
+		// SAFETY:
+		// - `elems` comes directly from `as_mut_slice` and is therefore valid.
+		// - Setting `self.len` before calling `drop_in_place` means that,
+		//   if an element's `Drop` impl panics, the vector's `Drop` impl will
+		//   do nothing (leaking the rest of the elements) instead of dropping
+		//   some twice.
+		unsafe {
+			self.len.set(0);
+			self.invalidate_handles();
+			ptr::drop_in_place(elems);
+		}
+	}
+
+	/// Invalidates every [`Handle`] issued so far.
+	///
+	/// This must be called by every method removing elements from the
+	/// vector or moving them to another index, so that a handle never
+	/// resolves to another element than the one it was issued for.
+	#[inline]
+	fn invalidate_handles(&self) {
+		self.generation.set(self.generation.get().wrapping_add(1))
+	}
+
+	/// Consumes the vector and maps each element with `f`, returning a new
+	/// vector with the same capacity.
+	///
+	/// If `f` panics, the elements already mapped and the ones not yet mapped
+	/// are all dropped exactly once.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(4);
+	/// vec.push(1);
+	/// vec.push(2);
+	/// vec.push(3);
+	///
+	/// let strings = vec.map_into(|i| i.to_string());
+	/// assert_eq!(strings, ["1", "2", "3"]);
+	/// assert_eq!(strings.len(), 3);
+	/// assert_eq!(strings.capacity(), 4);
+	/// ```
+	pub fn map_into<U, F: FnMut(T) -> U>(self, mut f: F) -> ConstVec<U> {
+		let mut result = ConstVec::new(self.capacity);
+		result.shrink_threshold = self.shrink_threshold;
+		self.into_iter().for_each(|item| result.push(f(item)));
+		result
+	}
+
+	/// Maps each element of the vector into a new vector, reusing the
+	/// allocation when possible.
+	///
+	/// If `T` and `U` have the same size, and the buffer is sufficiently
+	/// aligned for `U`, each mapped element is written in place of the
+	/// original one, and the buffer is handed over to the returned vector
+	/// without any allocation. Otherwise, this falls back to
+	/// [`map_into`](ConstVec::map_into).
+	///
+	/// The returned vector has the same capacity as this one. When the
+	/// buffer is reused, it keeps its original alignment, which may be
+	/// larger than the alignment of `U`: see
+	/// [`into_raw_parts`](ConstVec::into_raw_parts) before taking its
+	/// ownership.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(4);
+	/// vec.push(0x01020304u32);
+	/// vec.push(0x05060708);
+	/// let ptr = vec.as_ptr() as usize;
+	///
+	/// let bytes = vec.map_in_place(u32::to_be_bytes);
+	/// assert_eq!(bytes.as_ptr() as usize, ptr);
+	/// assert_eq!(bytes.capacity(), 4);
+	/// assert_eq!(bytes, [[1, 2, 3, 4], [5, 6, 7, 8]]);
+	/// assert_eq!(bytes.alloc_info().alignment, 4);
+	/// ```
+	///
+	/// Differing layouts require a new allocation:
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(3);
+	/// vec.push(1u8);
+	/// vec.push(2);
+	///
+	/// let wide = vec.map_in_place(|b| b as u64 * 1000);
+	/// assert_eq!(wide.capacity(), 3);
+	/// assert_eq!(wide, [1000, 2000]);
+	/// ```
+	pub fn map_in_place<U, F: FnMut(T) -> U>(self, mut f: F) -> ConstVec<U> {
+		if mem::size_of::<T>() != mem::size_of::<U>() || mem::align_of::<U>() > self.layout.align()
+		{
+			return self.map_into(f);
+		}
+
+		// Drops the mapped and the remaining elements if `f` panics. The
+		// vector itself is left empty so that it only deallocates the buffer.
+		struct Guard<T, U> {
+			vec: ConstVec<T>,
+			out: *mut U,
+			len: usize,
+			mapped: usize,
+		}
+
+		impl<T, U> Drop for Guard<T, U> {
+			fn drop(&mut self) {
+				// SAFETY: elements in `0..mapped` have been mapped, the one at
+				// `mapped` has been moved into `f`, and the following ones are
+				// still initialized.
+				unsafe {
+					ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.out, self.mapped));
+					ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+						self.vec.as_mut_ptr().add(self.mapped + 1),
+						self.len - self.mapped - 1,
+					));
+				}
+			}
+		}
+
+		let len = self.len();
+		self.len.set(0);
+
+		let mut guard = Guard {
+			out: self.ptr.as_ptr() as *mut U,
+			vec: self,
+			len,
+			mapped: 0,
+		};
+
+		while guard.mapped < len {
+			// SAFETY: `T` and `U` have the same size, the buffer is aligned for
+			// both, and the element at `mapped` is read exactly once before
+			// being overwritten.
+			unsafe {
+				let item = ptr::read(guard.vec.as_ptr().add(guard.mapped));
+				ptr::write(guard.out.add(guard.mapped), f(item));
+			}
+
+			guard.mapped += 1;
+		}
+
+		// SAFETY: the vector is read out of the guard, which is then
+		// forgotten, so its buffer is owned by exactly one vector.
+		let vec = ManuallyDrop::new(unsafe { ptr::read(&guard.vec) });
+		mem::forget(guard);
+
+		ConstVec {
+			ptr: vec.ptr.cast(),
+			capacity: vec.capacity,
+			layout: vec.layout,
+			len: Cell::new(len),
+			generation: Cell::new(0),
+			shrink_threshold: vec.shrink_threshold,
+		}
+	}
+
+	/// Retains only the elements specified by the predicate, which receives
+	/// the original index of each element along with a reference to it.
+	///
+	/// In other words, removes all elements `e` at index `i` such that
+	/// `f(i, &e)` returns `false`. This method operates in place, visiting
+	/// each element exactly once in the original order, and preserves the
+	/// order of the retained elements.
+	///
+	/// If `f` panics, the elements that were not yet visited are kept and
+	/// no element is dropped twice.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![0, 10, 20, 30, 40]);
+	/// vec.retain_indexed(|i, _| i % 2 == 0);
+	/// assert_eq!(vec, [0, 20, 40]);
+	/// ```
+	pub fn retain_indexed<F: FnMut(usize, &T) -> bool>(&mut self, mut f: F) {
+		let original_len = self.len();
+		self.invalidate_handles();
+
+		// Avoid double drop if `f` or an element `drop` panics.
+		self.len.set(0);
+
+		struct Guard<'a, T> {
+			vec: &'a mut ConstVec<T>,
+			processed: usize,
+			deleted: usize,
+			original_len: usize,
+		}
+
+		impl<'a, T> Drop for Guard<'a, T> {
+			fn drop(&mut self) {
+				if self.deleted > 0 {
+					// SAFETY: the unprocessed elements are shifted over the
+					// holes left by the deleted ones.
+					unsafe {
+						let base = self.vec.as_mut_ptr();
+						ptr::copy(
+							base.add(self.processed),
+							base.add(self.processed - self.deleted),
+							self.original_len - self.processed,
+						);
+					}
+				}
+
+				self.vec.len.set(self.original_len - self.deleted);
+			}
+		}
+
+		let mut g = Guard {
+			vec: self,
+			processed: 0,
+			deleted: 0,
+			original_len,
+		};
+
+		while g.processed != original_len {
+			// SAFETY: `processed` is in bounds, and the element it points to
+			// has not been moved or dropped yet.
+			unsafe {
+				let base = g.vec.as_mut_ptr();
+				let cur = base.add(g.processed);
+				if !f(g.processed, &*cur) {
+					g.processed += 1;
+					g.deleted += 1;
+					ptr::drop_in_place(cur);
+					continue;
+				}
+
+				if g.deleted > 0 {
+					ptr::copy_nonoverlapping(cur, base.add(g.processed - g.deleted), 1);
+				}
+
+				g.processed += 1;
+			}
+		}
+	}
+
+	/// Reorders the elements in place so that all the elements satisfying
+	/// `pred` come before all the elements that do not, and returns the
+	/// number of elements satisfying `pred`.
+	///
+	/// The relative order of the elements is not preserved, and no allocation
+	/// is performed.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(6);
+	/// for i in 1..=6 {
+	///     vec.push(i);
+	/// }
+	///
+	/// let pivot = vec.partition_in_place(|i| i % 2 == 0);
+	/// assert_eq!(pivot, 3);
+	///
+	/// let (even, odd) = vec.split_at_mut(pivot);
+	/// even.sort();
+	/// odd.sort();
+	/// assert_eq!(even, [2, 4, 6]);
+	/// assert_eq!(odd, [1, 3, 5]);
+	///
+	/// assert_eq!(vec.partition_in_place(|_| true), 6);
+	/// assert_eq!(vec.partition_in_place(|_| false), 0);
+	/// ```
+	pub fn partition_in_place<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> usize {
+		self.invalidate_handles();
+		let slice = self.as_mut_slice();
+		let mut pivot = 0;
+
+		for i in 0..slice.len() {
+			if pred(&slice[i]) {
+				slice.swap(pivot, i);
+				pivot += 1;
+			}
+		}
+
+		pivot
+	}
+
+	/// Converts the vector into a reference-counted slice.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// # use std::sync::Arc;
+	/// let vec = ConstVec::new(4);
+	/// vec.push(1);
+	/// vec.push(2);
+	/// vec.push(3);
+	///
+	/// let slice = vec.into_arc_slice();
+	/// let shared = Arc::clone(&slice);
+	/// assert_eq!(*shared, [1, 2, 3]);
+	/// assert!(Arc::ptr_eq(&slice, &shared));
+	/// ```
+	pub fn into_arc_slice(self) -> Arc<[T]> {
+		Arc::from(Vec::from(self))
+	}
+
+	/// Converts the vector into a single-threaded reference-counted slice.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// # use std::rc::Rc;
+	/// let vec = ConstVec::new(4);
+	/// vec.push(1);
+	/// vec.push(2);
+	/// vec.push(3);
+	///
+	/// let slice = vec.into_rc_slice();
+	/// let shared = Rc::clone(&slice);
+	/// assert_eq!(*shared, [1, 2, 3]);
+	/// assert!(Rc::ptr_eq(&slice, &shared));
+	/// ```
+	pub fn into_rc_slice(self) -> Rc<[T]> {
+		Rc::from(Vec::from(self))
+	}
+
+	/// Grows the capacity of the vector to `new_capacity`, reallocating the
+	/// buffer.
+	///
+	/// Does nothing if `new_capacity` is not greater than the current
+	/// capacity. Since it requires a mutable reference, no reference to the
+	/// elements may outlive the reallocation.
+	///
+	/// # Panics
+	///
+	/// Panics if the new allocated size in bytes exceeds `isize::MAX`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(1);
+	/// vec.push(1);
+	///
+	/// vec.grow_to(3);
+	/// vec.push(2);
+	/// vec.push(3);
+	///
+	/// assert_eq!(vec.capacity(), 3);
+	/// assert_eq!(vec, [1, 2, 3]);
+	/// ```
+	pub fn grow_to(&mut self, new_capacity: usize) {
+		if new_capacity > self.capacity {
+			self.reallocate(new_capacity)
+		}
+	}
+
+	/// Grows the capacity of the vector to `new_capacity`, returning an error
+	/// instead of panicking or aborting if the reallocation fails.
+	///
+	/// On error, the vector is left unchanged.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::{ConstVec, TryReserveError};
+	/// let mut vec = ConstVec::<u32>::new(1);
+	/// vec.try_grow_to(3).unwrap();
+	/// assert_eq!(vec.capacity(), 3);
+	///
+	/// assert_eq!(
+	///     vec.try_grow_to(usize::MAX).unwrap_err(),
+	///     TryReserveError::CapacityOverflow
+	/// );
+	/// assert_eq!(vec.capacity(), 3);
+	/// ```
+	pub fn try_grow_to(&mut self, new_capacity: usize) -> Result<(), TryReserveError> {
+		if new_capacity > self.capacity {
+			self.try_reallocate(new_capacity)
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Reallocates the buffer so that it holds exactly `new_capacity`
+	/// elements.
+	///
+	/// `new_capacity` must not be smaller than the current length.
+	fn reallocate(&mut self, new_capacity: usize) {
+		self.try_reallocate(new_capacity)
+			.unwrap_or_else(TryReserveError::handle)
+	}
+
+	/// Reallocates the buffer so that it holds exactly `new_capacity`
+	/// elements, leaving the vector unchanged on error.
+	///
+	/// `new_capacity` must not be smaller than the current length.
+	fn try_reallocate(&mut self, new_capacity: usize) -> Result<(), TryReserveError> {
+		debug_assert!(new_capacity >= self.len());
+		let new_layout = Layout::array::<T>(new_capacity)
+			.and_then(|layout| layout.align_to(self.layout.align()))
+			.map_err(|_| TryReserveError::CapacityOverflow)?;
+
+		let ptr = if new_layout.size() == 0 {
+			if self.layout.size() != 0 {
+				unsafe { alloc::dealloc(self.ptr.as_ptr() as *mut u8, self.layout) }
+			}
+
+			dangling(new_layout)
+		} else {
+			let ptr = unsafe {
+				if self.layout.size() == 0 {
+					alloc::alloc(new_layout)
+				} else {
+					alloc::realloc(self.ptr.as_ptr() as *mut u8, self.layout, new_layout.size())
+				}
+			};
+
+			NonNull::new(ptr as *mut T).ok_or(TryReserveError::AllocError { layout: new_layout })?
+		};
+
+		self.ptr = ptr;
+		self.capacity = new_capacity;
+		self.layout = new_layout;
+		Ok(())
+	}
+
+	/// Creates a new vector from an iterator, using its size hint to choose
+	/// the capacity.
+	///
+	/// The capacity is the upper bound of the iterator's
+	/// [`size_hint`](Iterator::size_hint). If there is no upper bound, the
+	/// elements are collected as with [`collect_const`], and the capacity
+	/// equals the length. This is mostly useful for [`ExactSizeIterator`]
+	/// sources.
+	///
+	/// # Panics
+	///
+	/// Panics if the iterator yields more elements than the upper bound of
+	/// its size hint, which is a bug in the iterator.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from_iter_sized(vec![1, 2, 3]);
+	/// assert_eq!(vec, [1, 2, 3]);
+	/// assert_eq!(vec.capacity(), 3);
+	///
+	/// let vec = ConstVec::from_iter_sized((0..10).filter(|i| i % 3 == 0));
+	/// assert_eq!(vec, [0, 3, 6, 9]);
+	/// assert_eq!(vec.capacity(), 10);
+	///
+	/// let vec = ConstVec::from_iter_sized((0..).take_while(|i| *i < 3));
+	/// assert_eq!(vec, [0, 1, 2]);
+	/// assert_eq!(vec.capacity(), 3);
+	/// ```
+	pub fn from_iter_sized<I: IntoIterator<Item = T>>(iter: I) -> ConstVec<T> {
+		let iter = iter.into_iter();
+		let result = match iter.size_hint() {
+			(_, Some(upper)) => Self::new(upper),
+			(_, None) => return collect_const(iter),
+		};
+
+		for item in iter {
+			result.push(item)
+		}
+
+		result
+	}
+
+	/// Creates a new vector from an exact-size iterator, with a capacity
+	/// equal to the iterator's [`len`](ExactSizeIterator::len).
+	///
+	/// # Panics
+	///
+	/// Panics if the iterator yields more elements than it reported. In
+	/// debug builds, also panics if it yields fewer.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from_exact_iter(0..4);
+	/// assert_eq!(vec, [0, 1, 2, 3]);
+	/// assert_eq!(vec.capacity(), vec.len());
+	///
+	/// let mut source = Vec::with_capacity(10);
+	/// source.extend(["a", "b"]);
+	/// let vec = ConstVec::from_exact_iter(source);
+	/// assert_eq!(vec, ["a", "b"]);
+	/// assert_eq!(vec.capacity(), vec.len());
+	/// ```
+	pub fn from_exact_iter<I: IntoIterator<Item = T>>(iter: I) -> ConstVec<T>
+	where
+		I::IntoIter: ExactSizeIterator,
+	{
+		let iter = iter.into_iter();
+		let result = Self::new(iter.len());
+
+		for item in iter {
+			result.push(item)
+		}
+
+		debug_assert!(
+			result.is_full(),
+			"iterator yielded fewer elements than its reported length"
+		);
+		result
+	}
+
+	/// Borrows the initialized elements as a [`Cow`], without cloning them.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// # use std::borrow::Cow;
+	/// let vec = ConstVec::new(3);
+	/// vec.push(1);
+	/// vec.push(2);
+	///
+	/// let cow = vec.as_cow();
+	/// assert!(matches!(cow, Cow::Borrowed(_)));
+	/// assert_eq!(cow, vec.as_slice());
+	/// ```
+	#[inline]
+	pub fn as_cow(&self) -> Cow<'_, [T]>
+	where
+		T: Clone,
+	{
+		Cow::Borrowed(self.as_slice())
+	}
+
+	/// Replaces the element at position `index` with `value`, returning the
+	/// old element.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(3);
+	/// vec.push(1);
+	/// vec.push(2);
+	///
+	/// assert_eq!(vec.replace(0, 10), 1);
+	/// assert_eq!(vec.replace(1, 20), 2);
+	/// assert_eq!(vec, [10, 20]);
+	/// ```
+	///
+	/// ```should_panic
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(3);
+	/// vec.push(1);
+	///
+	/// vec.replace(1, 2);
+	/// ```
+	#[inline]
+	pub fn replace(&mut self, index: usize, value: T) -> T {
+		mem::replace(&mut self.as_mut_slice()[index], value)
+	}
+
+	/// Replaces the element at position `index` with its default value,
+	/// returning the original element.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(3);
+	/// vec.push(1);
+	/// vec.push(2);
+	///
+	/// assert_eq!(vec.take(1), 2);
+	/// assert_eq!(vec, [1, 0]);
+	/// ```
+	#[inline]
+	pub fn take(&mut self, index: usize) -> T
+	where
+		T: Default,
+	{
+		self.replace(index, T::default())
+	}
+
+	/// Returns the currently initialized elements as a slice whose bounds are
+	/// fixed at call time.
+	///
+	/// This is the same slice as [`as_slice`](ConstVec::as_slice), but makes
+	/// explicit that the result does not follow the live length of the
+	/// vector: elements pushed afterward through a shared reference are not
+	/// visible through it. The same holds for iterators over `&ConstVec`,
+	/// which are built from such a slice.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(3);
+	/// vec.push(1);
+	///
+	/// let snapshot = vec.snapshot();
+	/// let iter = vec.iter();
+	/// vec.push(2);
+	/// vec.push(3);
+	///
+	/// assert_eq!(snapshot, [1]);
+	/// assert_eq!(iter.count(), 1);
+	/// assert_eq!(vec, [1, 2, 3]);
+	/// ```
+	#[inline]
+	pub fn snapshot(&self) -> &[T] {
+		self.as_slice()
+	}
+
+	/// Returns `true` if `needle` is a prefix of the vector.
+	///
+	/// Always returns `true` if `needle` is empty.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![10, 40, 30]);
+	/// assert!(vec.starts_with(&[10]));
+	/// assert!(vec.starts_with(&[10, 40]));
+	/// assert!(!vec.starts_with(&[50]));
+	/// assert!(!vec.starts_with(&[10, 50]));
+	/// assert!(vec.starts_with(&[]));
+	/// ```
+	#[inline]
+	pub fn starts_with(&self, needle: &[T]) -> bool
+	where
+		T: PartialEq,
+	{
+		self.as_slice().starts_with(needle)
+	}
+
+	/// Returns `true` if `needle` is a suffix of the vector.
+	///
+	/// Always returns `true` if `needle` is empty.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![10, 40, 30]);
+	/// assert!(vec.ends_with(&[30]));
+	/// assert!(vec.ends_with(&[40, 30]));
+	/// assert!(!vec.ends_with(&[50]));
+	/// assert!(!vec.ends_with(&[50, 30]));
+	/// assert!(vec.ends_with(&[]));
+	/// ```
+	#[inline]
+	pub fn ends_with(&self, needle: &[T]) -> bool
+	where
+		T: PartialEq,
+	{
+		self.as_slice().ends_with(needle)
+	}
+
+	/// Returns `true` if the elements of the vector are equal to the ones
+	/// yielded by `iter`, in the same order.
+	///
+	/// The iterator is consumed lazily, and comparison stops at the first
+	/// mismatch. Returns `false` if the iterator yields more or fewer
+	/// elements than the vector contains.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 4, 9]);
+	/// assert!(vec.eq_iter((1..=3).map(|i| i * i)));
+	/// assert!(!vec.eq_iter((1..=2).map(|i| i * i)));
+	/// assert!(!vec.eq_iter((1..=4).map(|i| i * i)));
+	/// assert!(!vec.eq_iter(vec![1, 4, 8]));
+	///
+	/// assert!(ConstVec::<u32>::new(0).eq_iter(std::iter::empty::<u32>()));
+	/// ```
+	pub fn eq_iter<I: IntoIterator>(&self, iter: I) -> bool
+	where
+		T: PartialEq<I::Item>,
+	{
+		let mut iter = iter.into_iter();
+
+		for item in self.iter() {
+			match iter.next() {
+				Some(other) if *item == other => (),
+				_ => return false,
+			}
+		}
+
+		iter.next().is_none()
+	}
+
+	/// Returns an iterator over the subslices separated by elements that
+	/// match `pred`. The matched element is not contained in the subslices.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(b"GET /index.html HTTP/1.1".to_vec());
+	///
+	/// let mut parts = vec.split(|b| *b == b' ');
+	/// assert_eq!(parts.next(), Some(&b"GET"[..]));
+	/// assert_eq!(parts.next(), Some(&b"/index.html"[..]));
+	/// assert_eq!(parts.next(), Some(&b"HTTP/1.1"[..]));
+	/// assert_eq!(parts.next(), None);
+	/// ```
+	#[inline]
+	pub fn split<F: FnMut(&T) -> bool>(&self, pred: F) -> std::slice::Split<'_, T, F> {
+		self.as_slice().split(pred)
+	}
+
+	/// Returns an iterator over at most `n` subslices separated by elements
+	/// that match `pred`. The last subslice contains the remainder of the
+	/// vector.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(b"key=value=more".to_vec());
+	///
+	/// let mut parts = vec.splitn(2, |b| *b == b'=');
+	/// assert_eq!(parts.next(), Some(&b"key"[..]));
+	/// assert_eq!(parts.next(), Some(&b"value=more"[..]));
+	/// assert_eq!(parts.next(), None);
+	/// ```
+	#[inline]
+	pub fn splitn<F: FnMut(&T) -> bool>(&self, n: usize, pred: F) -> std::slice::SplitN<'_, T, F> {
+		self.as_slice().splitn(n, pred)
+	}
+
+	/// Consumes the vector and maps each element with the fallible function
+	/// `f`, returning a new vector with the same capacity, or the first error
+	/// returned by `f`.
+	///
+	/// On error, the elements already mapped and the ones not yet mapped are
+	/// all dropped exactly once.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec!["1", "2", "3"]);
+	/// let numbers: ConstVec<u32> = vec.try_map_into(str::parse).unwrap();
+	/// assert_eq!(numbers, [1, 2, 3]);
+	///
+	/// let vec = ConstVec::from(vec!["1", "two", "3"]);
+	/// assert!(vec.try_map_into(str::parse::<u32>).is_err());
+	/// ```
+	pub fn try_map_into<U, E, F: FnMut(T) -> Result<U, E>>(
+		self,
+		mut f: F,
+	) -> Result<ConstVec<U>, E> {
+		let mut result = ConstVec::new(self.capacity);
+		result.shrink_threshold = self.shrink_threshold;
+
+		for item in self {
+			result.push(f(item)?)
+		}
+
+		Ok(result)
+	}
+
+	/// Clones the elements of `self` into `target`, reusing its allocation.
+	///
+	/// The previous elements of `target` are dropped, and its capacity is
+	/// grown with [`grow_to`](ConstVec::grow_to) only if it is smaller than
+	/// the length of `self`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2, 3]);
+	///
+	/// let mut target = ConstVec::new(5);
+	/// target.push(42);
+	/// let ptr = target.as_ptr();
+	///
+	/// vec.clone_into(&mut target);
+	/// assert_eq!(target, [1, 2, 3]);
+	/// assert_eq!(target.capacity(), 5);
+	/// assert_eq!(target.as_ptr(), ptr);
+	/// ```
+	pub fn clone_into(&self, target: &mut ConstVec<T>)
+	where
+		T: Clone,
+	{
+		target.clear();
+		target.grow_to(self.len());
+
+		for item in self {
+			target.push(item.clone())
+		}
+	}
+
+	/// Moves the elements in the range `[at, len)` to the end of `dest`,
+	/// truncating `self` to `at` elements.
+	///
+	/// Contrarily to creating a new vector for the tail, this reuses the
+	/// allocation of `dest`, which is only grown if needed.
+	///
+	/// # Panics
+	///
+	/// Panics if `at > len`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![1, 2, 3, 4, 5]);
+	/// let mut dest = vec![0];
+	///
+	/// vec.drain_tail_into(2, &mut dest);
+	/// assert_eq!(vec, [1, 2]);
+	/// assert_eq!(vec.capacity(), 5);
+	/// assert_eq!(dest, [0, 3, 4, 5]);
+	/// ```
+	pub fn drain_tail_into(&mut self, at: usize, dest: &mut Vec<T>) {
+		let len = self.len();
+		assert!(
+			at <= len,
+			"`at` split index (is {}) should be <= len (is {})",
+			at,
+			len
+		);
+
+		let count = len - at;
+		dest.reserve(count);
+
+		if count > 0 {
+			self.invalidate_handles()
+		}
+
+		// SAFETY: the moved elements are removed from `self` before being
+		// added to `dest`, which has enough spare capacity to hold them.
+		unsafe {
+			self.len.set(at);
+			ptr::copy_nonoverlapping(
+				self.as_ptr().add(at),
+				dest.as_mut_ptr().add(dest.len()),
+				count,
+			);
+			dest.set_len(dest.len() + count);
+		}
+	}
+
+	/// Removes the elements past `len` and returns them in a new [`Vec`].
+	///
+	/// Returns an empty `Vec` if `len` is greater than or equal to the
+	/// current length. This has no effect on the capacity of the vector.
+	///
+	/// If any element is removed, every [`Handle`] of the vector is
+	/// invalidated, as with [`clear`](ConstVec::clear), so that a handle to
+	/// a removed element never resolves to an element pushed afterward.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![1, 2, 3, 4]);
+	///
+	/// assert_eq!(vec.truncate_to_vec(1), [2, 3, 4]);
+	/// assert_eq!(vec, [1]);
+	/// assert_eq!(vec.capacity(), 4);
+	///
+	/// assert_eq!(vec.truncate_to_vec(3), []);
+	/// assert_eq!(vec, [1]);
+	///
+	/// let handle = vec.push_handle(2);
+	/// vec.truncate_to_vec(1);
+	/// vec.push(3);
+	/// assert!(vec.get_handle(handle).is_none());
+	/// ```
+	pub fn truncate_to_vec(&mut self, len: usize) -> Vec<T> {
+		let mut tail = Vec::new();
+
+		if len < self.len() {
+			self.drain_tail_into(len, &mut tail)
+		}
+
+		tail
+	}
+
+	/// Moves all the elements out of the vector into a new [`Vec`], leaving
+	/// it empty.
+	///
+	/// This is the same as [`truncate_to_vec(0)`](ConstVec::truncate_to_vec):
+	/// like [`clear`](ConstVec::clear), it has no effect on the capacity of
+	/// the vector, and invalidates its [`Handle`]s unless it was already
+	/// empty. No element is dropped.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// # use std::rc::Rc;
+	/// let item = Rc::new(1);
+	/// let mut vec = ConstVec::new(4);
+	/// vec.push(item.clone());
+	/// let handle = vec.push_handle(item.clone());
+	///
+	/// let drained = vec.drain_to_vec();
+	/// assert!(vec.is_empty());
+	/// assert_eq!(vec.capacity(), 4);
+	/// assert_eq!(drained.len(), 2);
+	/// assert_eq!(Rc::strong_count(&item), 3);
+	///
+	/// vec.push(item.clone());
+	/// vec.push(item.clone());
+	/// assert!(vec.get_handle(handle).is_none());
+	/// ```
+	pub fn drain_to_vec(&mut self) -> Vec<T> {
+		self.truncate_to_vec(0)
+	}
+
+	/// Removes the first `at` elements of the vector and returns them in a
+	/// new vector of capacity `at`.
+	///
+	/// The remaining elements are shifted to the front of `self`, which
+	/// keeps its capacity. This is useful to dequeue batches of elements in
+	/// FIFO order.
+	///
+	/// # Panics
+	///
+	/// Panics if `at > len`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![1, 2, 3, 4, 5]);
+	///
+	/// let front = vec.split_off_front(2);
+	/// assert_eq!(front, [1, 2]);
+	/// assert_eq!(vec, [3, 4, 5]);
+	/// assert_eq!(vec.capacity(), 5);
+	///
+	/// assert!(vec.split_off_front(0).is_empty());
+	/// assert_eq!(vec, [3, 4, 5]);
+	///
+	/// assert_eq!(vec.split_off_front(3), [3, 4, 5]);
+	/// assert!(vec.is_empty());
+	/// ```
+	pub fn split_off_front(&mut self, at: usize) -> ConstVec<T> {
+		let len = self.len();
+		assert!(
+			at <= len,
+			"`at` split index (is {}) should be <= len (is {})",
+			at,
+			len
+		);
+
+		let front = ConstVec::new(at);
+
+		// SAFETY: the first `at` elements are moved to `front`, which has
+		// room for them, and the following ones are moved to the front of
+		// `self`.
+		unsafe {
+			let ptr = self.as_mut_ptr();
+			ptr::copy_nonoverlapping(ptr, front.ptr.as_ptr(), at);
+			front.len.set(at);
+			ptr::copy(ptr.add(at), ptr, len - at);
+			self.len.set(len - at);
+		}
+
+		if at > 0 {
+			self.invalidate_handles()
+		}
+
+		front
+	}
+
+	/// Removes the leading run of elements satisfying `pred` and returns
+	/// them in a [`Vec`].
+	///
+	/// Elements are tested in order, stopping at the first one for which
+	/// `pred` returns `false`. The remaining elements are shifted to the
+	/// front of the vector.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![1, 3, 4, 5, 6]);
+	///
+	/// assert_eq!(vec.drain_prefix_while(|x| x % 2 == 0), []);
+	/// assert_eq!(vec, [1, 3, 4, 5, 6]);
+	///
+	/// assert_eq!(vec.drain_prefix_while(|x| x % 2 == 1), [1, 3]);
+	/// assert_eq!(vec, [4, 5, 6]);
+	///
+	/// assert_eq!(vec.drain_prefix_while(|x| *x > 0), [4, 5, 6]);
+	/// assert!(vec.is_empty());
+	/// ```
+	pub fn drain_prefix_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+		let len = self.len();
+		let count = self.iter().take_while(|item| pred(item)).count();
+		let mut prefix = Vec::with_capacity(count);
+
+		// SAFETY: the first `count` elements are moved to `prefix`, which has
+		// room for them, and the following ones are moved to the front of
+		// the vector.
+		unsafe {
+			let ptr = self.as_mut_ptr();
+			ptr::copy_nonoverlapping(ptr, prefix.as_mut_ptr(), count);
+			prefix.set_len(count);
+			ptr::copy(ptr.add(count), ptr, len - count);
+			self.len.set(len - count);
+		}
+
+		if count > 0 {
+			self.invalidate_handles()
+		}
+
+		prefix
+	}
+
+	/// Keeps the first `n` elements of the vector, dropping the others.
+	///
+	/// Does nothing if `n` is greater than or equal to the length. This has
+	/// no effect on the capacity of the vector.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![0, 1, 2, 3, 4]);
+	///
+	/// vec.keep_first(2);
+	/// assert_eq!(vec, [0, 1]);
+	///
+	/// vec.keep_first(3);
+	/// assert_eq!(vec.len(), 2);
+	/// assert_eq!(vec.capacity(), 5);
+	/// ```
+	pub fn keep_first(&mut self, n: usize) {
+		let len = self.len();
+		if n < len {
+			let ptr = self.as_mut_ptr();
+
+			// SAFETY: the length is updated before dropping the tail so that a
+			// panicking `Drop` impl cannot cause a double drop.
+			unsafe {
+				self.len.set(n);
+				self.invalidate_handles();
+				ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr.add(n), len - n));
+			}
+		}
+	}
+
+	/// Keeps the last `n` elements of the vector, dropping the others and
+	/// shifting the kept ones to the front.
+	///
+	/// Does nothing if `n` is greater than or equal to the length. This has
+	/// no effect on the capacity of the vector.
+	///
+	/// If dropping an element panics, the kept elements are leaked.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![0, 1, 2, 3, 4]);
+	///
+	/// vec.keep_last(2);
+	/// assert_eq!(vec, [3, 4]);
+	///
+	/// vec.keep_last(3);
+	/// assert_eq!(vec.len(), 2);
+	/// assert_eq!(vec.capacity(), 5);
+	/// ```
+	pub fn keep_last(&mut self, n: usize) {
+		let len = self.len();
+		if n < len {
+			let count = len - n;
+			let ptr = self.as_mut_ptr();
+
+			// SAFETY: the length is cleared before dropping the first `count`
+			// elements so that a panicking `Drop` impl cannot cause a double
+			// drop, then the last `n` elements are moved to the front.
+			unsafe {
+				self.len.set(0);
+				self.invalidate_handles();
+				ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr, count));
+				ptr::copy(ptr.add(count), ptr, n);
+				self.len.set(n);
+			}
+		}
+	}
+
+	/// Returns the initialized elements of a pinned vector as a pinned slice.
+	///
+	/// Elements of a `ConstVec` are address-stable: the buffer is never
+	/// reallocated through a shared reference, and [`push`](ConstVec::push)
+	/// only writes past the initialized region, so it never moves the
+	/// existing elements. Once the vector is pinned, no mutable reference to
+	/// it can be obtained anymore (unless `T` is [`Unpin`]), which means the
+	/// elements stay in place until they are dropped by the vector's `Drop`
+	/// implementation. This is exactly the guarantee required by [`Pin`], and
+	/// it still holds for elements pushed after the vector was pinned.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// # use std::marker::PhantomPinned;
+	/// # use std::pin::Pin;
+	/// struct Pinned(u32, PhantomPinned);
+	///
+	/// let vec = Box::pin(ConstVec::new(2));
+	/// vec.push(Pinned(1, PhantomPinned));
+	/// vec.push(Pinned(2, PhantomPinned));
+	///
+	/// let slice: Pin<&[Pinned]> = vec.as_ref().as_pin_slice();
+	/// assert_eq!(slice.len(), 2);
+	/// assert_eq!(slice[1].0, 2);
+	/// ```
+	#[inline]
+	pub fn as_pin_slice(self: Pin<&Self>) -> Pin<&[T]> {
+		// SAFETY: see the address-stability invariant documented above.
+		unsafe { self.map_unchecked(ConstVec::as_slice) }
+	}
+
+	/// Appends an element to the back of the vector, and returns a [`Handle`]
+	/// to it.
+	///
+	/// # Panics
+	///
+	/// Panics if the vector is full.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(2);
+	/// let a = vec.push_handle('a');
+	/// let b = vec.push_handle('b');
+	///
+	/// assert_eq!(b.index(), 1);
+	/// assert_eq!(vec.get_handle(a), Some(&'a'));
+	/// assert_eq!(vec.get_handle(b), Some(&'b'));
+	///
+	/// vec.clear();
+	/// vec.push('c');
+	/// assert_eq!(vec.get_handle(a), None);
+	/// ```
+	#[inline]
+	pub fn push_handle(&self, value: T) -> Handle {
+		let index = self.len();
+		self.push(value);
+		Handle {
+			index,
+			generation: self.generation.get(),
+		}
+	}
+
+	/// Returns a reference to the element designated by the given handle, or
+	/// `None` if elements have been removed or moved since the handle was
+	/// issued.
+	///
+	/// See [`push_handle`](ConstVec::push_handle) and [`Handle`].
+	#[inline]
+	pub fn get_handle(&self, handle: Handle) -> Option<&T> {
+		if handle.generation == self.generation.get() {
+			self.as_slice().get(handle.index)
+		} else {
+			None
+		}
+	}
+
+	/// Shrinks the capacity of the vector as much as possible, reallocating
+	/// the buffer so that its capacity equals its length.
+	///
+	/// If the vector is empty, the buffer is deallocated, as with
+	/// [`release_if_empty`](ConstVec::release_if_empty).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(10);
+	/// vec.push(1);
+	/// vec.push(2);
+	///
+	/// vec.shrink_to_fit();
+	/// assert_eq!(vec.capacity(), 2);
+	/// assert_eq!(vec, [1, 2]);
+	///
+	/// vec.clear();
+	/// vec.shrink_to_fit();
+	/// assert_eq!(vec.capacity(), 0);
+	/// assert_eq!(vec.alloc_info().bytes_allocated, 0);
+	/// ```
+	#[inline]
+	pub fn shrink_to_fit(&mut self) {
+		self.shrink_to(0)
+	}
+
+	/// Deallocates the buffer of the vector if it is empty, leaving it with
+	/// a capacity of `0`.
+	///
+	/// Returns `true` if the buffer was released, or `false` if the vector
+	/// is not empty or already has no capacity.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(1024);
+	/// vec.push(1u64);
+	///
+	/// assert!(!vec.release_if_empty());
+	/// assert_eq!(vec.capacity(), 1024);
+	///
+	/// vec.clear();
+	/// assert!(vec.release_if_empty());
+	/// assert_eq!(vec.capacity(), 0);
+	/// assert_eq!(vec.alloc_info().bytes_allocated, 0);
+	///
+	/// assert!(!vec.release_if_empty());
+	/// ```
+	pub fn release_if_empty(&mut self) -> bool {
+		if self.is_empty() && self.capacity > 0 {
+			self.shrink_to_fit();
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Shrinks the capacity of the vector as much as possible, returning an
+	/// error instead of aborting if the reallocation fails.
+	///
+	/// On error, the vector is left unchanged.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(10);
+	/// vec.push(1);
+	///
+	/// vec.try_shrink_to_fit().unwrap();
+	/// assert_eq!(vec.capacity(), 1);
+	/// ```
+	pub fn try_shrink_to_fit(&mut self) -> Result<(), TryReserveError> {
+		if self.len() < self.capacity {
+			self.try_reallocate(self.len())
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Shrinks the capacity of the vector with a lower bound.
+	///
+	/// The capacity will remain at least as large as both the length and the
+	/// supplied value. If the current capacity is less than the lower limit,
+	/// this is a no-op.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(10);
+	/// vec.push(1);
+	/// vec.push(2);
+	/// vec.push(3);
+	///
+	/// vec.shrink_to(4);
+	/// assert_eq!(vec.capacity(), 4);
+	///
+	/// vec.shrink_to(0);
+	/// assert_eq!(vec.capacity(), 3);
+	/// assert_eq!(vec, [1, 2, 3]);
+	/// ```
+	pub fn shrink_to(&mut self, min_capacity: usize) {
+		let new_capacity = self.len().max(min_capacity);
+		if new_capacity < self.capacity {
+			self.reallocate(new_capacity)
+		}
+	}
+
+	/// Clones the elements of the vector into a new [`Vec`].
+	///
+	/// This is an alias of [`to_vec`](slice::to_vec).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![String::from("a"), String::from("b")]);
+	/// assert_eq!(vec.cloned_vec(), ["a", "b"]);
+	/// ```
+	#[inline]
+	pub fn cloned_vec(&self) -> Vec<T>
+	where
+		T: Clone,
+	{
+		self.as_slice().to_vec()
+	}
+
+	/// Copies the elements of the vector into a new [`Vec`], using a single
+	/// memory copy.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2, 3]);
+	/// assert_eq!(vec.copied_vec(), [1, 2, 3]);
+	/// assert_eq!(vec.copied_vec(), vec.cloned_vec());
+	/// ```
+	#[inline]
+	pub fn copied_vec(&self) -> Vec<T>
+	where
+		T: Copy,
+	{
+		let mut result = Vec::with_capacity(self.len());
+		result.extend_from_slice(self.as_slice());
+		result
+	}
+
+	/// Appends an element to the back of the vector if there is enough
+	/// capacity left, and returns a reference to it.
+	///
+	/// Returns `None` if the vector is full, in which case `value` is
+	/// dropped.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(1);
+	///
+	/// let pushed = vec.checked_push(String::from("a"));
+	/// assert_eq!(pushed.map(String::as_str), Some("a"));
+	///
+	/// assert_eq!(vec.checked_push(String::from("b")), None);
+	/// assert_eq!(vec, ["a"]);
+	/// ```
+	#[inline]
+	pub fn checked_push(&self, value: T) -> Option<&T> {
+		self.try_push_get(value).ok()
+	}
+
+	/// Appends an element to the back of the vector if there is enough
+	/// capacity left, and returns a reference to it.
+	///
+	/// Unlike [`checked_push`](ConstVec::checked_push), the value is given
+	/// back in the `Err` variant if the vector is full, so it is never lost.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(1);
+	///
+	/// let pushed = vec.try_push_get(String::from("a")).unwrap();
+	/// assert_eq!(pushed, "a");
+	///
+	/// let rejected = vec.try_push_get(String::from("b")).unwrap_err();
+	/// assert_eq!(rejected, "b");
+	/// assert_eq!(vec, ["a"]);
+	/// ```
+	#[inline]
+	pub fn try_push_get(&self, value: T) -> Result<&T, T> {
+		if self.len() < self.capacity() {
+			let index = self.len();
+			self.push(value);
+			// SAFETY: the element at `index` has just been initialized, and
+			// is never moved through a shared reference.
+			Ok(unsafe { &*self.as_ptr().add(index) })
+		} else {
+			Err(value)
+		}
+	}
+
+	/// Checks that `additional` more elements can be pushed into the vector,
+	/// without modifying it.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(3);
+	/// vec.push(1);
+	///
+	/// assert!(vec.ensure_capacity(2).is_ok());
+	///
+	/// let error = vec.ensure_capacity(3).unwrap_err();
+	/// assert_eq!(error.requested(), 3);
+	/// assert_eq!(error.remaining(), 2);
+	/// ```
+	#[inline]
+	pub fn ensure_capacity(&self, additional: usize) -> Result<(), CapacityError> {
+		let remaining = self.remaining_capacity();
+		if additional <= remaining {
+			Ok(())
+		} else {
+			Err(CapacityError {
+				requested: additional,
+				remaining,
+			})
+		}
+	}
+
+	/// Returns the initialized elements as a slice of [`Cell`]s, allowing them
+	/// to be updated through shared references.
+	///
+	/// This requires a mutable reference: since [`as_slice`] hands out plain
+	/// shared references to the elements from `&self`, mutating them through
+	/// cells obtained from `&self` would be unsound. The returned slice can
+	/// however be shared freely for as long as it lives.
+	///
+	/// [`as_slice`]: ConstVec::as_slice
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![1, 2, 3]);
+	///
+	/// let cells = vec.as_slice_of_cells();
+	/// let (a, b) = (&cells[0], &cells[0]);
+	/// a.set(10);
+	/// assert_eq!(b.get(), 10);
+	///
+	/// assert_eq!(vec, [10, 2, 3]);
+	/// ```
+	#[inline]
+	pub fn as_slice_of_cells(&mut self) -> &[Cell<T>] {
+		Cell::from_mut(self.as_mut_slice()).as_slice_of_cells()
+	}
+
+	/// Clones the vector, returning an error instead of panicking or aborting
+	/// if the allocation fails.
+	///
+	/// The clone has the same capacity and buffer alignment as the original
+	/// (see [`new_aligned`](ConstVec::new_aligned)).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(3);
+	/// vec.push(1);
+	///
+	/// let cloned = vec.try_clone().unwrap();
+	/// assert_eq!(cloned, [1]);
+	/// assert_eq!(cloned.capacity(), 3);
+	///
+	/// let vec = ConstVec::<u8>::new_aligned(8, 64);
+	/// let cloned = vec.try_clone().unwrap();
+	/// assert_eq!(cloned.alloc_info().alignment, 64);
+	/// assert_eq!(cloned.as_ptr() as usize % 64, 0);
+	/// ```
+	pub fn try_clone(&self) -> Result<ConstVec<T>, TryReserveError>
+	where
+		T: Clone,
+	{
+		let mut result = Self::try_with_layout(self.capacity, self.layout)?;
+		result.shrink_threshold = self.shrink_threshold;
+
+		for item in self {
+			result.push(item.clone())
+		}
+
+		Ok(result)
+	}
+
+	/// Splits the vector into two new vectors holding its first and second
+	/// halves, and frees the original allocation.
+	///
+	/// If the length is odd, the first half gets the extra element. The
+	/// capacity of each half equals its length.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2, 3, 4]);
+	/// let (a, b) = vec.split_into_halves();
+	/// assert_eq!(a, [1, 2]);
+	/// assert_eq!(b, [3, 4]);
+	///
+	/// let vec = ConstVec::from(vec![1, 2, 3, 4, 5]);
+	/// let (a, b) = vec.split_into_halves();
+	/// assert_eq!(a, [1, 2, 3]);
+	/// assert_eq!(a.capacity(), 3);
+	/// assert_eq!(b, [4, 5]);
+	/// assert_eq!(b.capacity(), 2);
+	/// ```
+	pub fn split_into_halves(self) -> (ConstVec<T>, ConstVec<T>) {
+		let len = self.len();
+		let mid = len - len / 2;
+		let first = ConstVec::new(mid);
+		let second = ConstVec::new(len - mid);
+
+		// SAFETY: both halves have exactly enough capacity for the moved
+		// elements, which are removed from `self` so that dropping it only
+		// frees its allocation.
+		unsafe {
+			self.len.set(0);
+			ptr::copy_nonoverlapping(self.as_ptr(), first.ptr.as_ptr(), mid);
+			first.len.set(mid);
+			ptr::copy_nonoverlapping(self.as_ptr().add(mid), second.ptr.as_ptr(), len - mid);
+			second.len.set(len - mid);
+		}
+
+		(first, second)
+	}
+
+	/// Creates a new vector by repeating the elements of this one `n` times.
+	///
+	/// The capacity of the result equals its length. Unlike the
+	/// [`repeat`](slice::repeat) method of slices, available through
+	/// [`Deref`], this returns a `ConstVec` rather than a [`Vec`].
+	///
+	/// # Panics
+	///
+	/// Panics if the resulting capacity would overflow.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2]);
+	///
+	/// assert!(vec.repeat_const(0).is_empty());
+	/// assert_eq!(vec.repeat_const(1), [1, 2]);
+	///
+	/// let tiled = vec.repeat_const(3);
+	/// assert_eq!(tiled, [1, 2, 1, 2, 1, 2]);
+	/// assert_eq!(tiled.capacity(), 6);
+	///
+	/// assert!(ConstVec::<u8>::new(0).repeat_const(usize::MAX).is_empty());
+	/// assert_eq!(ConstVec::from(vec![()]).repeat_const(usize::MAX).len(), usize::MAX);
+	/// ```
+	///
+	/// ```should_panic
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2]);
+	/// vec.repeat_const(usize::MAX);
+	/// ```
+	pub fn repeat_const(&self, n: usize) -> ConstVec<T>
+	where
+		T: Copy,
+	{
+		let capacity = self.len().checked_mul(n).expect("capacity overflow");
+		let result = ConstVec::new(capacity);
+
+		if self.is_empty() {
+			return result;
+		}
+
+		if mem::size_of::<T>() == 0 {
+			// SAFETY: zero-sized values need no initialization, and copying
+			// them has no effect.
+			result.len.set(capacity);
+			return result;
+		}
+
+		for _ in 0..n {
+			// SAFETY: `result` has room for `n` copies of `self`.
+			unsafe { result.append_elements(self.as_slice()) }
+		}
+
+		result
+	}
+
+	/// Returns an iterator over `size` elements of the vector at a time,
+	/// starting at the end.
+	///
+	/// If the length is not divisible by `size`, the last chunk yielded
+	/// holds the remaining first elements.
+	///
+	/// # Panics
+	///
+	/// Panics if `size` is 0.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2, 3, 4, 5]);
+	///
+	/// let mut chunks = vec.rchunks(2);
+	/// assert_eq!(chunks.next(), Some(&[4, 5][..]));
+	/// assert_eq!(chunks.next(), Some(&[2, 3][..]));
+	/// assert_eq!(chunks.next(), Some(&[1][..]));
+	/// assert_eq!(chunks.next(), None);
+	/// ```
+	#[inline]
+	pub fn rchunks(&self, size: usize) -> std::slice::RChunks<'_, T> {
+		self.as_slice().rchunks(size)
+	}
+
+	/// Returns an iterator over the subslices separated by elements that
+	/// match `pred`, starting at the end of the vector. The matched element
+	/// is not contained in the subslices.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(b"a,b,c".to_vec());
+	///
+	/// let mut parts = vec.rsplit(|b| *b == b',');
+	/// assert_eq!(parts.next(), Some(&b"c"[..]));
+	/// assert_eq!(parts.next(), Some(&b"b"[..]));
+	/// assert_eq!(parts.next(), Some(&b"a"[..]));
+	/// assert_eq!(parts.next(), None);
+	/// ```
+	#[inline]
+	pub fn rsplit<F: FnMut(&T) -> bool>(&self, pred: F) -> std::slice::RSplit<'_, T, F> {
+		self.as_slice().rsplit(pred)
+	}
+
+	/// Checks once that `count` more elements fit in the vector, and returns
+	/// a [`Builder`] that can push them without checking the capacity of the
+	/// vector again.
+	///
+	/// The builder borrows the vector mutably, so that nothing else can push
+	/// into it and use up the checked capacity in the meantime.
+	///
+	/// # Panics
+	///
+	/// Panics if the remaining capacity is less than `count`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(4);
+	/// vec.push(0);
+	///
+	/// let mut builder = vec.builder(3);
+	/// for i in 1..4 {
+	///     builder.push(i);
+	/// }
+	///
+	/// assert_eq!(vec, [0, 1, 2, 3]);
+	/// ```
+	///
+	/// ```should_panic
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(4);
+	///
+	/// let mut builder = vec.builder(1);
+	/// builder.push(1);
+	/// builder.push(2);
+	/// ```
+	#[inline]
+	pub fn builder(&mut self, count: usize) -> Builder<'_, T> {
+		if let Err(e) = self.ensure_capacity(count) {
+			panic!("{}", e)
+		}
+
+		Builder {
+			vec: self,
+			remaining: count,
+		}
+	}
+
+	/// Consumes the vector into an iterator yielding each element along with
+	/// its index.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec!['a', 'b', 'c']);
+	///
+	/// let mut iter = vec.into_enumerated();
+	/// assert_eq!(iter.next_back(), Some((2, 'c')));
+	/// assert_eq!(iter.collect::<Vec<_>>(), [(0, 'a'), (1, 'b')]);
+	/// ```
+	#[inline]
+	pub fn into_enumerated(
+		self,
+	) -> impl DoubleEndedIterator<Item = (usize, T)> + ExactSizeIterator {
+		self.into_iter().enumerate()
+	}
+
+	/// Creates an iterator which uses a closure to determine if an element
+	/// should be removed.
+	///
+	/// If the closure returns `true`, the element is removed from the vector
+	/// and yielded. If the closure returns `false`, the element remains in
+	/// the vector and will not be yielded by the iterator. The closure can
+	/// mutate every element it is given.
+	///
+	/// If the returned iterator is not exhausted, e.g. because it is dropped
+	/// without iterating or the iteration short-circuits, then the remaining
+	/// elements are retained.
+	///
+	/// The order of the retained elements is preserved, and the capacity of
+	/// the vector is unchanged.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from((1..=8).collect::<Vec<_>>());
+	///
+	/// let evens: Vec<_> = vec.extract_if(|x| *x % 2 == 0).collect();
+	/// assert_eq!(evens, [2, 4, 6, 8]);
+	/// assert_eq!(vec, [1, 3, 5, 7]);
+	/// assert_eq!(vec.capacity(), 8);
+	/// ```
+	///
+	/// Partial consumption:
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from((1..=8).collect::<Vec<_>>());
+	///
+	/// let mut iter = vec.extract_if(|x| *x % 2 == 0);
+	/// assert_eq!(iter.next(), Some(2));
+	/// assert_eq!(iter.next(), Some(4));
+	/// drop(iter);
+	///
+	/// assert_eq!(vec, [1, 3, 5, 6, 7, 8]);
+	/// ```
+	pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, T, F> {
+		let old_len = self.len();
+		self.invalidate_handles();
+
+		// Guard against the vector getting leaked (leak amplification).
+		self.len.set(0);
+
+		ExtractIf {
+			vec: self,
+			idx: 0,
+			del: 0,
+			old_len,
+			pred,
+		}
+	}
+
+	/// Returns the two raw pointers spanning the initialized elements.
+	///
+	/// See [`slice::as_ptr_range`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::<u32>::new(8);
+	/// vec.push(1);
+	/// vec.push(2);
+	/// vec.push(3);
+	///
+	/// let range = vec.as_ptr_range();
+	/// assert_eq!(range.start, vec.as_ptr());
+	/// assert_eq!(
+	///     range.end as usize - range.start as usize,
+	///     vec.len() * std::mem::size_of::<u32>()
+	/// );
+	/// ```
+	#[inline]
+	pub fn as_ptr_range(&self) -> Range<*const T> {
+		self.as_slice().as_ptr_range()
+	}
+
+	/// Returns an iterator over raw pointers to the initialized elements of
+	/// the vector.
+	///
+	/// The buffer is never reallocated through a shared reference, so the
+	/// pointers stay valid as long as the vector is not mutably borrowed or
+	/// dropped, even if more elements are pushed. Elements pushed after
+	/// this call are not yielded.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(4);
+	/// vec.push(1u32);
+	/// vec.push(2);
+	/// vec.push(3);
+	///
+	/// let ptrs: Vec<*const u32> = vec.iter_raw().collect();
+	/// vec.push(4);
+	///
+	/// assert_eq!(ptrs.len(), 3);
+	/// assert_eq!(ptrs[0], vec.as_ptr());
+	/// for (i, ptr) in ptrs.iter().enumerate() {
+	///     assert_eq!(*ptr, vec.as_ptr().wrapping_add(i));
+	///     assert_eq!(unsafe { **ptr }, i as u32 + 1);
+	/// }
+	/// ```
+	#[inline]
+	pub fn iter_raw(&self) -> impl ExactSizeIterator<Item = *const T> {
+		let ptr = self.as_ptr();
+		(0..self.len()).map(move |i| ptr.wrapping_add(i))
+	}
+
+	/// Returns the two unsafe mutable pointers spanning the initialized
+	/// elements.
+	///
+	/// See [`slice::as_mut_ptr_range`].
+	#[inline]
+	pub fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
+		self.as_mut_slice().as_mut_ptr_range()
+	}
+
+	/// Returns an iterator over the runs of adjacent elements for which
+	/// `pred` returns `true` on every consecutive pair.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 1, 2, 3, 3, 3, 1]);
+	///
+	/// let runs: Vec<_> = vec.chunk_by(|a, b| a == b).collect();
+	/// assert_eq!(runs, [&[1, 1][..], &[2], &[3, 3, 3], &[1]]);
+	/// ```
+	#[inline]
+	pub fn chunk_by<F: FnMut(&T, &T) -> bool>(&self, pred: F) -> std::slice::ChunkBy<'_, T, F> {
+		self.as_slice().chunk_by(pred)
+	}
+
+	/// Converts the vector into a boxed slice, dropping any excess capacity.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(10);
+	/// vec.push(1);
+	/// vec.push(2);
+	///
+	/// let boxed = vec.into_boxed_slice();
+	/// assert_eq!(*boxed, [1, 2]);
+	/// ```
+	#[inline]
+	pub fn into_boxed_slice(self) -> Box<[T]> {
+		Vec::from(self).into_boxed_slice()
+	}
+
+	/// Checks if the elements of the vector are sorted.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// assert!(ConstVec::from(vec![1, 2, 2, 9]).is_sorted());
+	/// assert!(!ConstVec::from(vec![1, 3, 2, 4]).is_sorted());
+	/// assert!(ConstVec::from(vec![0]).is_sorted());
+	/// assert!(ConstVec::<i32>::new(0).is_sorted());
+	/// ```
+	#[inline]
+	pub fn is_sorted(&self) -> bool
+	where
+		T: PartialOrd,
+	{
+		self.as_slice().is_sorted()
+	}
+
+	/// Checks if the elements of the vector are sorted using the given
+	/// comparator function, which must return `true` if its two arguments
+	/// are in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![9, 4, 4, 1]);
+	/// assert!(vec.is_sorted_by(|a, b| a >= b));
+	/// assert!(!vec.is_sorted_by(|a, b| a <= b));
+	/// ```
+	#[inline]
+	pub fn is_sorted_by<F: FnMut(&T, &T) -> bool>(&self, compare: F) -> bool {
+		self.as_slice().is_sorted_by(compare)
+	}
+
+	/// Checks if the elements of the vector are sorted using the given key
+	/// extraction function.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec!["c", "bb", "aaa"]);
+	/// assert!(vec.is_sorted_by_key(|s| s.len()));
+	/// assert!(!vec.is_sorted());
+	/// ```
+	#[inline]
+	pub fn is_sorted_by_key<K: PartialOrd, F: FnMut(&T) -> K>(&self, f: F) -> bool {
+		self.as_slice().is_sorted_by_key(f)
+	}
+
+	/// Clears the vector, dropping its elements, and returns it while
+	/// keeping its allocation.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// # use std::rc::Rc;
+	/// let item = Rc::new(0);
+	/// let vec = ConstVec::new(4);
+	/// vec.push(Rc::clone(&item));
+	/// vec.push(Rc::clone(&item));
+	///
+	/// let vec = vec.cleared();
+	/// assert!(vec.is_empty());
+	/// assert_eq!(vec.capacity(), 4);
+	/// assert_eq!(Rc::strong_count(&item), 1);
+	/// ```
+	#[inline]
+	pub fn cleared(mut self) -> Self {
+		self.clear();
+		self
+	}
+
+	/// Appends an element to the back of the vector, evicting the oldest
+	/// element if the vector is full.
+	///
+	/// When the vector is full, the first element is removed and returned,
+	/// and the other ones are shifted toward the front to make room for
+	/// `value`. Otherwise this behaves like [`push`](ConstVec::push) and
+	/// returns `None`. This makes the vector a simple bounded FIFO.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(3);
+	/// assert_eq!(vec.push_ring(1), None);
+	/// assert_eq!(vec.push_ring(2), None);
+	/// assert_eq!(vec.push_ring(3), None);
+	///
+	/// assert_eq!(vec.push_ring(4), Some(1));
+	/// assert_eq!(vec.push_ring(5), Some(2));
+	/// assert_eq!(vec, [3, 4, 5]);
+	/// ```
+	pub fn push_ring(&mut self, value: T) -> Option<T> {
+		let len = self.len();
+		if len < self.capacity() {
+			self.push(value);
+			None
+		} else if len == 0 {
+			Some(value)
+		} else {
+			// SAFETY: the vector is full and non-empty, so the oldest element
+			// can be moved out and replaced by shifting the others.
+			unsafe {
+				let base = self.as_mut_ptr();
+				let oldest = ptr::read(base);
+				ptr::copy(base.add(1), base, len - 1);
+				ptr::write(base.add(len - 1), value);
+				self.invalidate_handles();
+				Some(oldest)
+			}
+		}
+	}
+
+	/// Calls `f` with exclusive mutable access to the initialized elements,
+	/// and returns its result.
+	///
+	/// This is equivalent to calling `f(self.as_mut_slice())`, but makes the
+	/// window of exclusive access explicit: no reference obtained through a
+	/// shared-reference method such as [`get_handle`] or [`checked_push`]
+	/// can be alive while `f` runs, and none can be created from inside it.
+	/// This is the recommended way to mutate elements in place.
+	///
+	/// [`get_handle`]: ConstVec::get_handle
+	/// [`checked_push`]: ConstVec::checked_push
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![1, 2, 3]);
+	///
+	/// let sum = vec.scoped_mut(|elements| {
+	///     for e in elements.iter_mut() {
+	///         *e *= 10;
+	///     }
+	///
+	///     elements.iter().sum::<i32>()
+	/// });
+	///
+	/// assert_eq!(sum, 60);
+	/// assert_eq!(vec, [10, 20, 30]);
+	/// ```
+	#[inline]
+	pub fn scoped_mut<R>(&mut self, f: impl FnOnce(&mut [T]) -> R) -> R {
+		f(self.as_mut_slice())
+	}
+
+	/// Sums the elements of the vector.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2, 3, 4]);
+	/// assert_eq!(vec.sum::<i32>(), 10);
+	/// ```
+	#[inline]
+	pub fn sum<'a, S: Sum<&'a T>>(&'a self) -> S {
+		self.iter().sum()
+	}
+
+	/// Multiplies the elements of the vector.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2, 3, 4]);
+	/// assert_eq!(vec.product::<i32>(), 24);
+	/// ```
+	#[inline]
+	pub fn product<'a, P: Product<&'a T>>(&'a self) -> P {
+		self.iter().product()
+	}
+
+	/// Returns the maximum element of the vector, or `None` if it is empty.
+	///
+	/// If several elements are equally maximum, the last one is returned.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// assert_eq!(ConstVec::from(vec![3, 9, 1]).max(), Some(&9));
+	/// assert_eq!(ConstVec::<i32>::new(0).max(), None);
+	/// ```
+	#[inline]
+	pub fn max(&self) -> Option<&T>
+	where
+		T: Ord,
+	{
+		self.iter().max()
+	}
+
+	/// Returns the minimum element of the vector, or `None` if it is empty.
+	///
+	/// If several elements are equally minimum, the first one is returned.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// assert_eq!(ConstVec::from(vec![3, 9, 1]).min(), Some(&1));
+	/// assert_eq!(ConstVec::<i32>::new(0).min(), None);
+	/// ```
+	#[inline]
+	pub fn min(&self) -> Option<&T>
+	where
+		T: Ord,
+	{
+		self.iter().min()
+	}
+
+	/// Returns the element that gives the maximum value from the given key
+	/// function, or `None` if the vector is empty.
+	///
+	/// If several elements are equally maximum, the last one is returned.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![-3i32, 2, -10, 5]);
+	/// assert_eq!(vec.max_by_key(|i| i.abs()), Some(&-10));
+	/// assert_eq!(ConstVec::<i32>::new(0).max_by_key(|i| i.abs()), None);
+	/// ```
+	#[inline]
+	pub fn max_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<&T> {
+		self.iter().max_by_key(|item| f(item))
+	}
+
+	/// Returns the element that gives the minimum value from the given key
+	/// function, or `None` if the vector is empty.
+	///
+	/// If several elements are equally minimum, the first one is returned.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![-3i32, 2, -10, 5]);
+	/// assert_eq!(vec.min_by_key(|i| i.abs()), Some(&2));
+	/// assert_eq!(ConstVec::<i32>::new(0).min_by_key(|i| i.abs()), None);
+	/// ```
+	#[inline]
+	pub fn min_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<&T> {
+		self.iter().min_by_key(|item| f(item))
+	}
+
+	/// Freezes the vector, and returns a [`Handle`] to each of its elements.
+	///
+	/// Since a frozen vector can never be cleared, the returned handles stay
+	/// valid for as long as it lives.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec!["a", "b", "c"]);
+	/// let (frozen, handles) = vec.into_frozen_handles();
+	///
+	/// let resolved: Vec<_> = handles.iter().map(|h| *frozen.get_handle(*h).unwrap()).collect();
+	/// assert_eq!(resolved, ["a", "b", "c"]);
+	/// ```
+	pub fn into_frozen_handles(self) -> (FrozenConstVec<T>, Vec<Handle>) {
+		let generation = self.generation.get();
+		let handles = (0..self.len())
+			.map(|index| Handle { index, generation })
+			.collect();
+
+		(FrozenConstVec(self), handles)
+	}
+
+	/// Removes all duplicate elements, keeping only the first occurrence of
+	/// each value.
+	///
+	/// Contrarily to [`slice::sort`] followed by a consecutive
+	/// deduplication, this preserves the order of the retained elements. The
+	/// removed elements are dropped exactly once.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![3, 1, 3, 2, 1, 4, 2]);
+	/// vec.dedup_unsorted();
+	/// assert_eq!(vec, [3, 1, 2, 4]);
+	/// ```
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// # use std::rc::Rc;
+	/// let mut vec = ConstVec::new(4);
+	/// let a = Rc::new("a");
+	/// let b = Rc::new("b");
+	/// vec.push(Rc::clone(&a));
+	/// vec.push(Rc::clone(&b));
+	/// vec.push(Rc::clone(&a));
+	/// vec.push(Rc::clone(&a));
+	///
+	/// vec.dedup_unsorted();
+	/// assert_eq!(vec, [Rc::new("a"), Rc::new("b")]);
+	/// assert_eq!(Rc::strong_count(&a), 2);
+	/// assert_eq!(Rc::strong_count(&b), 2);
+	/// ```
+	pub fn dedup_unsorted(&mut self)
+	where
+		T: Eq + Hash,
+	{
+		let keep: Vec<bool> = {
+			let mut seen = HashSet::with_capacity(self.len());
+			self.iter().map(|item| seen.insert(item)).collect()
+		};
+
+		self.retain_indexed(|i, _| keep[i])
+	}
+
+	/// Returns a reference to the elements as an array, if the length of the
+	/// vector is exactly `N`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2, 3]);
+	///
+	/// assert_eq!(vec.as_array_ref::<3>(), Some(&[1, 2, 3]));
+	/// assert_eq!(vec.as_array_ref::<2>(), None);
+	/// ```
+	#[inline]
+	pub fn as_array_ref<const N: usize>(&self) -> Option<&[T; N]> {
+		<&[T; N]>::try_from(self.as_slice()).ok()
+	}
+
+	/// Writes `value` into the spare capacity slot at `index`, without
+	/// changing the length of the vector.
+	///
+	/// This allows initializing the elements out of order, before making them
+	/// visible with [`commit_len`](ConstVec::commit_len). Writing twice
+	/// into the same slot leaks the previous value.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is not less than the capacity.
+	///
+	/// # Safety
+	///
+	/// `index` must not be less than the current length: initialized elements
+	/// may be borrowed, and must not be overwritten through a shared
+	/// reference.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(3);
+	///
+	/// unsafe {
+	///     vec.write_at(2, 'c');
+	///     vec.write_at(0, 'a');
+	///     vec.write_at(1, 'b');
+	///     vec.commit_len(3);
+	/// }
+	///
+	/// assert_eq!(vec, ['a', 'b', 'c']);
+	/// ```
+	#[inline]
+	pub unsafe fn write_at(&self, index: usize, value: T) {
+		assert!(index < self.capacity, "index out of capacity");
+		ptr::write(self.ptr.as_ptr().add(index), value)
+	}
+
+	/// Sets the length of the vector to `new_len`, after its elements have
+	/// been initialized with [`write_at`](ConstVec::write_at).
+	///
+	/// # Panics
+	///
+	/// Panics if `new_len` is less than the current length, or greater than
+	/// the capacity.
+	///
+	/// # Safety
+	///
+	/// Every slot in `[0, new_len)` must be initialized.
+	#[inline]
+	pub unsafe fn commit_len(&self, new_len: usize) {
+		assert!(
+			new_len >= self.len(),
+			"cannot shrink the length through a shared reference"
+		);
+		assert!(new_len <= self.capacity, "length out of capacity");
+		self.len.set(new_len)
+	}
+
+	/// Returns the initialized elements of the vector, along with a raw
+	/// pointer to the start of its spare capacity.
+	///
+	/// This lets a producer write elements into the spare capacity while
+	/// the committed elements are being read, and then publish them with
+	/// [`commit_len`](ConstVec::commit_len). Readers only ever see the
+	/// committed elements.
+	///
+	/// The [`remaining_capacity`](ConstVec::remaining_capacity) slots after
+	/// the returned pointer may be written. Each slot must be fully
+	/// initialized before `commit_len` covers it, and must not be written
+	/// again afterwards. Since the length of a `ConstVec` is not atomic, the
+	/// vector is not [`Sync`], and producer and readers must live on the
+	/// same thread.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(4);
+	/// vec.push(1);
+	///
+	/// let (committed, spare) = vec.committed_and_spare();
+	/// assert_eq!(committed, [1]);
+	///
+	/// unsafe {
+	///     spare.write(2);
+	///     spare.add(1).write(3);
+	/// }
+	///
+	/// assert_eq!(committed, [1]);
+	/// assert_eq!(vec, [1]);
+	///
+	/// unsafe { vec.commit_len(3) };
+	/// assert_eq!(vec, [1, 2, 3]);
+	/// ```
+	#[inline]
+	pub fn committed_and_spare(&self) -> (&[T], *mut T) {
+		// SAFETY: `len <= capacity`, so the pointer is at most one past the
+		// end of the buffer.
+		let spare = unsafe { self.ptr.as_ptr().add(self.len()) };
+		(self.as_slice(), spare)
+	}
+
+	/// Replaces the element at `index` with `value` through a shared
+	/// reference, and returns the previous element.
+	///
+	/// Returns `Err(value)` if `index` is not less than the length.
+	///
+	/// # Safety
+	///
+	/// No reference to the element at `index` may be alive: elements
+	/// borrowed through a shared reference to the vector (for instance with
+	/// [`as_slice`](ConstVec::as_slice) or
+	/// [`checked_push`](ConstVec::checked_push)) must not be overwritten.
+	///
+	/// Unless `T` is [`Unpin`], the vector must not be pinned, since this
+	/// would move a pinned element out of it (see
+	/// [`as_pin_slice`](ConstVec::as_pin_slice)).
+	///
+	/// Use [`replace`](ConstVec::replace) when a mutable reference is
+	/// available.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(3);
+	/// vec.push(1);
+	/// vec.push(2);
+	///
+	/// unsafe {
+	///     assert_eq!(vec.set(1, 3), Ok(2));
+	///     assert_eq!(vec.set(2, 4), Err(4));
+	/// }
+	///
+	/// assert_eq!(vec, [1, 3]);
+	/// ```
+	#[inline]
+	pub unsafe fn set(&self, index: usize, value: T) -> Result<T, T> {
+		if index < self.len() {
+			Ok(ptr::replace(self.ptr.as_ptr().add(index), value))
+		} else {
+			Err(value)
+		}
+	}
+
+	/// Sorts the vector in place through a shared reference.
+	///
+	/// This sort is not stable. The elements are sorted through a mutable
+	/// slice built from the shared reference, which stays live for the whole
+	/// sort.
+	///
+	/// The [`Copy`] bound is not needed for panic safety, since
+	/// [`sort_unstable`](slice::sort_unstable) never duplicates or loses an
+	/// element when a comparison panics. It only restricts this method to
+	/// plain values, like [`reverse_shared`](ConstVec::reverse_shared).
+	///
+	/// # Safety
+	///
+	/// No reference to the elements of the vector may be alive, for
+	/// instance one obtained with [`as_slice`](ConstVec::as_slice) or
+	/// [`checked_push`](ConstVec::checked_push), since the elements are
+	/// moved around.
+	///
+	/// The [`Ord::cmp`] implementation of `T` must not access the vector
+	/// while sorting, for instance by reading or pushing to it through
+	/// another shared reference, since that would alias the mutable slice.
+	///
+	/// Unless `T` is [`Unpin`], the vector must not be pinned, since this
+	/// would move pinned elements (see
+	/// [`as_pin_slice`](ConstVec::as_pin_slice)). Note that [`Copy`] does
+	/// not imply [`Unpin`].
+	///
+	/// Use [`sort_unstable`](slice::sort_unstable) when a mutable reference
+	/// is available.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![3, 1, 2]);
+	/// let shared = &vec;
+	///
+	/// unsafe { shared.sort_shared() };
+	/// assert_eq!(vec, [1, 2, 3]);
+	/// ```
+	#[inline]
+	pub unsafe fn sort_shared(&self)
+	where
+		T: Ord + Copy,
+	{
+		std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len()).sort_unstable()
+	}
+
+	/// Reverses the order of the elements of the vector in place, through a
+	/// shared reference.
+	///
+	/// # Safety
+	///
+	/// No reference to the elements of the vector may be alive, for
+	/// instance one obtained with [`as_slice`](ConstVec::as_slice) or
+	/// [`checked_push`](ConstVec::checked_push), since the elements are
+	/// moved around.
+	///
+	/// Unless `T` is [`Unpin`], the vector must not be pinned, since this
+	/// would move pinned elements (see
+	/// [`as_pin_slice`](ConstVec::as_pin_slice)). Note that [`Copy`] does
+	/// not imply [`Unpin`].
+	///
+	/// Use [`reverse`](slice::reverse) when a mutable reference is
+	/// available.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2, 3]);
+	/// let shared = &vec;
+	///
+	/// unsafe { shared.reverse_shared() };
+	/// assert_eq!(vec, [3, 2, 1]);
+	/// ```
+	#[inline]
+	pub unsafe fn reverse_shared(&self)
+	where
+		T: Copy,
+	{
+		std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len()).reverse()
+	}
+
+	/// Returns the whole buffer of the vector, including its spare capacity,
+	/// as a slice of `MaybeUninit<T>`.
+	///
+	/// The first [`len`](ConstVec::len) elements are initialized. Reading
+	/// them through `MaybeUninit` is sound; the remaining slots must be
+	/// assumed uninitialized.
+	///
+	/// This takes `&mut self` because the spare slots may otherwise be
+	/// written by [`push`](ConstVec::push) while borrowed.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(4);
+	/// vec.push(1);
+	/// vec.push(2);
+	///
+	/// let buffer = vec.as_uninit_slice();
+	/// assert_eq!(buffer.len(), 4);
+	/// assert_eq!(unsafe { buffer[1].assume_init() }, 2);
+	/// ```
+	#[inline]
+	pub fn as_uninit_slice(&mut self) -> &[MaybeUninit<T>] {
+		// SAFETY: the buffer holds `capacity` slots, and `MaybeUninit<T>` has
+		// the same layout as `T`.
+		unsafe {
+			std::slice::from_raw_parts(self.ptr.as_ptr() as *const MaybeUninit<T>, self.capacity)
+		}
+	}
+
+	/// Returns the whole buffer of the vector, including its spare capacity,
+	/// as a mutable slice of `MaybeUninit<T>`.
+	///
+	/// This allows initializing the buffer in any order before making the
+	/// elements visible with [`commit_len`](ConstVec::commit_len).
+	/// Overwriting an initialized element leaks the previous value.
+	///
+	/// # Safety
+	///
+	/// The first [`len`](ConstVec::len) elements must still be initialized
+	/// when the returned borrow ends: they must not be replaced with
+	/// [`MaybeUninit::uninit`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// # use std::mem::MaybeUninit;
+	/// let mut vec = ConstVec::new(4);
+	/// vec.push(0);
+	///
+	/// unsafe {
+	///     for (i, slot) in vec.as_uninit_slice_mut().iter_mut().enumerate().rev() {
+	///         *slot = MaybeUninit::new(i * 10);
+	///     }
+	///
+	///     vec.commit_len(4);
+	/// }
+	///
+	/// assert_eq!(vec, [0, 10, 20, 30]);
+	/// ```
+	#[inline]
+	pub unsafe fn as_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<T>] {
+		std::slice::from_raw_parts_mut(self.ptr.as_ptr() as *mut MaybeUninit<T>, self.capacity)
+	}
+
+	/// Consumes the vector into an iterator yielding its elements from back
+	/// to front.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2, 3]);
+	///
+	/// let drained: Vec<_> = vec.into_iter_rev().collect();
+	/// assert_eq!(drained, [3, 2, 1]);
+	/// ```
+	#[inline]
+	pub fn into_iter_rev(self) -> impl DoubleEndedIterator<Item = T> + ExactSizeIterator {
+		self.into_iter().rev()
+	}
+
+	/// Asserts, in debug builds only, that `additional` more elements can be
+	/// pushed into the vector.
+	///
+	/// This is a no-op in release builds, and can be used to document
+	/// capacity invariants at batch boundaries.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if the remaining capacity is less than
+	/// `additional`, with a message giving both counts.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// # use std::panic::{self, AssertUnwindSafe};
+	/// let vec = ConstVec::new(3);
+	/// vec.push(1);
+	/// vec.assert_fits(2);
+	///
+	/// if cfg!(debug_assertions) {
+	///     let error = panic::catch_unwind(AssertUnwindSafe(|| vec.assert_fits(3))).unwrap_err();
+	///     let message = error.downcast_ref::<String>().unwrap();
+	///     assert!(message.contains("requested 3"));
+	///     assert!(message.contains("remaining 2"));
+	/// }
+	/// ```
+	#[inline]
+	pub fn assert_fits(&self, additional: usize) {
+		if cfg!(debug_assertions) {
+			if let Err(e) = self.ensure_capacity(additional) {
+				panic!("{}", e)
+			}
+		}
+	}
+
+	/// Pushes default values until the vector is full.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(5);
+	/// vec.push(1);
+	/// vec.push(2);
+	///
+	/// vec.fill_remaining_default();
+	/// assert_eq!(vec, [1, 2, 0, 0, 0]);
+	/// assert_eq!(vec.len(), vec.capacity());
+	/// ```
+	pub fn fill_remaining_default(&self)
+	where
+		T: Default,
+	{
+		while self.len() < self.capacity() {
+			self.push(T::default())
+		}
+	}
+
+	/// Consumes the vector into an iterator over `N` elements at a time, as
+	/// arrays.
+	///
+	/// If the length is not divisible by `N`, the last elements are not
+	/// yielded, but can be recovered with [`IntoArrayChunks::remainder`] or
+	/// [`IntoArrayChunks::into_remainder`]. They are dropped along with the
+	/// iterator otherwise.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2, 3, 4]);
+	/// let mut chunks = vec.into_array_chunks::<2>();
+	/// assert_eq!(chunks.next(), Some([1, 2]));
+	/// assert_eq!(chunks.next(), Some([3, 4]));
+	/// assert_eq!(chunks.next(), None);
+	/// assert!(chunks.remainder().is_empty());
+	///
+	/// let vec = ConstVec::from(vec![1, 2, 3, 4, 5]);
+	/// let mut chunks = vec.into_array_chunks::<2>();
+	/// assert_eq!(chunks.remainder(), [5]);
+	/// assert_eq!(chunks.by_ref().collect::<Vec<_>>(), [[1, 2], [3, 4]]);
+	/// assert_eq!(chunks.into_remainder().collect::<Vec<_>>(), [5]);
+	/// ```
+	#[inline]
+	pub fn into_array_chunks<const N: usize>(self) -> IntoArrayChunks<T, N> {
+		assert!(N != 0, "chunk size must be non-zero");
+		IntoArrayChunks {
+			iter: self.into_iter(),
+		}
+	}
+
+	/// Copies all the elements of `src` into the vector.
+	///
+	/// # Panics
+	///
+	/// Panics if the two slices have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![0; 3]);
+	/// vec.copy_from_slice(&[1, 2, 3]);
+	/// assert_eq!(vec, [1, 2, 3]);
+	/// ```
+	///
+	/// ```should_panic
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![0; 3]);
+	/// vec.copy_from_slice(&[1, 2]);
+	/// ```
+	#[inline]
+	pub fn copy_from_slice(&mut self, src: &[T])
+	where
+		T: Copy,
+	{
+		self.as_mut_slice().copy_from_slice(src)
+	}
+
+	/// Swaps all the elements of the vector with those in `other`.
+	///
+	/// # Panics
+	///
+	/// Panics if the two slices have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![1, 2, 3]);
+	/// let mut other = [4, 5, 6];
+	///
+	/// vec.swap_with_slice(&mut other);
+	/// assert_eq!(vec, [4, 5, 6]);
+	/// assert_eq!(other, [1, 2, 3]);
+	/// ```
+	///
+	/// ```should_panic
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![1, 2, 3]);
+	/// vec.swap_with_slice(&mut [4]);
+	/// ```
+	#[inline]
+	pub fn swap_with_slice(&mut self, other: &mut [T]) {
+		self.as_mut_slice().swap_with_slice(other)
+	}
+
+	/// Swaps the buffers of the two vectors, along with their lengths and
+	/// capacities, in O(1).
+	///
+	/// This is equivalent to [`mem::swap`], and no element is moved.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut a = ConstVec::new(4);
+	/// a.push(1);
+	///
+	/// let mut b = ConstVec::new(8);
+	/// b.push(2);
+	/// b.push(3);
+	/// b.push(4);
+	///
+	/// let ptr = a.as_ptr();
+	/// a.swap_contents(&mut b);
+	///
+	/// assert_eq!(a, [2, 3, 4]);
+	/// assert_eq!(a.capacity(), 8);
+	/// assert_eq!(b, [1]);
+	/// assert_eq!(b.capacity(), 4);
+	/// assert_eq!(b.as_ptr(), ptr);
+	/// ```
+	#[inline]
+	pub fn swap_contents(&mut self, other: &mut ConstVec<T>) {
+		mem::swap(self, other)
+	}
+
+	/// Overwrites the elements in the given range with clones of `value`.
+	///
+	/// The previous elements are dropped. Only initialized elements can be
+	/// overwritten, so this never changes the length of the vector.
+	///
+	/// # Panics
+	///
+	/// Panics if the starting point is greater than the end point or if the
+	/// end point is greater than the length of the vector.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![1, 2, 3, 4, 5]);
+	/// vec.fill_range(1..4, 0);
+	/// assert_eq!(vec, [1, 0, 0, 0, 5]);
+	///
+	/// vec.fill_range(3.., 9);
+	/// assert_eq!(vec, [1, 0, 0, 9, 9]);
+	/// ```
+	///
+	/// ```should_panic
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(10);
+	/// vec.push(1);
+	/// vec.push(2);
+	/// vec.fill_range(1..3, 0);
+	/// ```
+	#[inline]
+	pub fn fill_range<R: RangeBounds<usize>>(&mut self, range: R, value: T)
+	where
+		T: Clone,
+	{
+		let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+		self.as_mut_slice()[bounds].fill(value)
+	}
+
+	/// Inserts `value` at the position keeping the vector sorted, and returns
+	/// its index.
+	///
+	/// The vector must already be sorted. The insertion point is found with a
+	/// binary search, and the following elements are shifted to the right.
+	/// If the vector already contains elements equal to `value`, it may be
+	/// inserted next to any of them.
+	///
+	/// # Panics
+	///
+	/// Panics if the vector is full.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::new(5);
+	/// assert_eq!(vec.binary_insert(5), 0);
+	/// assert_eq!(vec.binary_insert(1), 0);
+	/// assert_eq!(vec.binary_insert(9), 2);
+	/// assert_eq!(vec.binary_insert(3), 1);
+	/// assert_eq!(vec.binary_insert(7), 3);
+	///
+	/// assert_eq!(vec, [1, 3, 5, 7, 9]);
+	/// assert!(vec.is_sorted());
+	/// ```
+	pub fn binary_insert(&mut self, value: T) -> usize
+	where
+		T: Ord,
+	{
+		let len = self.len();
+		if len == self.capacity() {
+			panic!("not enough capacity")
+		}
+
+		let index = match self.binary_search(&value) {
+			Ok(index) | Err(index) => index,
+		};
+
+		// SAFETY: there is room for one more element, and `index <= len`.
+		unsafe {
+			let p = self.as_mut_ptr().add(index);
+			ptr::copy(p, p.add(1), len - index);
+			ptr::write(p, value);
+			self.len.set(len + 1);
+		}
+
+		if index < len {
+			self.invalidate_handles()
+		}
+
+		index
+	}
+
+	/// Returns an iterator over `size` elements of the vector at a time,
+	/// starting at the beginning, leaving out the last elements if they do
+	/// not fill a whole chunk.
+	///
+	/// Those elements can be retrieved with the
+	/// [`remainder`](std::slice::ChunksExact::remainder) method of the
+	/// returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if `size` is 0.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2, 3, 4, 5]);
+	///
+	/// let mut chunks = vec.chunks_exact(2);
+	/// assert_eq!(chunks.next(), Some(&[1, 2][..]));
+	/// assert_eq!(chunks.next(), Some(&[3, 4][..]));
+	/// assert_eq!(chunks.next(), None);
+	/// assert_eq!(chunks.remainder(), [5]);
+	/// ```
+	#[inline]
+	pub fn chunks_exact(&self, size: usize) -> std::slice::ChunksExact<'_, T> {
+		self.as_slice().chunks_exact(size)
+	}
+
+	/// Removes all but the first of consecutive elements in the vector
+	/// satisfying a given equality relation.
+	///
+	/// The `same_bucket` function is passed references to two elements from
+	/// the vector and must determine if the elements compare equal. The
+	/// elements are passed in opposite order from their order in the vector,
+	/// so if `same_bucket(a, b)` returns `true`, `a` is removed.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec!["foo", "bar", "Bar", "baz", "bar"]);
+	/// vec.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+	/// assert_eq!(vec, ["foo", "bar", "baz", "bar"]);
+	/// ```
+	pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+		let len = self.len();
+		if len <= 1 {
+			return;
+		}
+
+		self.invalidate_handles();
+
+		// Fills the gap between the retained and the unprocessed elements if
+		// `same_bucket` or an element `drop` panics.
+		struct FillGapOnDrop<'a, T> {
+			/// Index of the next element to process.
+			read: usize,
+
+			/// Index of the next hole to fill.
+			write: usize,
+
+			vec: &'a mut ConstVec<T>,
+		}
+
+		impl<'a, T> Drop for FillGapOnDrop<'a, T> {
+			fn drop(&mut self) {
+				// SAFETY: elements in `read..len` are initialized and are moved
+				// to the holes starting at `write`.
+				unsafe {
+					let ptr = self.vec.as_mut_ptr();
+					let len = self.vec.len();
+					ptr::copy(ptr.add(self.read), ptr.add(self.write), len - self.read);
+					self.vec.len.set(len - (self.read - self.write));
+				}
+			}
+		}
+
+		let mut gap = FillGapOnDrop {
+			read: 1,
+			write: 1,
+			vec: self,
+		};
+
+		let ptr = gap.vec.as_mut_ptr();
+
+		// SAFETY: `write <= read < len` in the loop, elements in
+		// `read..len` are initialized, and so are the retained ones in
+		// `0..write`.
+		unsafe {
+			while gap.read < len {
+				let read_ptr = ptr.add(gap.read);
+				let prev_ptr = ptr.add(gap.write - 1);
+
+				if same_bucket(&mut *read_ptr, &mut *prev_ptr) {
+					// Increase `read` first so the element is not dropped
+					// again if its `drop` panics.
+					gap.read += 1;
+					ptr::drop_in_place(read_ptr);
+				} else {
+					ptr::copy(read_ptr, ptr.add(gap.write), 1);
+					gap.write += 1;
+					gap.read += 1;
+				}
+			}
+
+			gap.vec.len.set(gap.write);
+			mem::forget(gap);
+		}
+	}
+
+	/// Removes consecutive repeated elements in the vector.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![1, 2, 2, 3, 2]);
+	/// vec.dedup();
+	/// assert_eq!(vec, [1, 2, 3, 2]);
+	/// ```
+	#[inline]
+	pub fn dedup(&mut self)
+	where
+		T: PartialEq,
+	{
+		self.dedup_by(|a, b| a == b)
+	}
+
+	/// Removes consecutive repeated elements in the vector, and returns the
+	/// number of removed elements.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let mut vec = ConstVec::from(vec![1, 1, 2, 3, 3, 3, 1]);
+	/// assert_eq!(vec.dedup_returning_count(), 3);
+	/// assert_eq!(vec, [1, 2, 3, 1]);
+	///
+	/// assert_eq!(vec.dedup_returning_count(), 0);
+	/// assert_eq!(vec, [1, 2, 3, 1]);
+	/// ```
+	#[inline]
+	pub fn dedup_returning_count(&mut self) -> usize
+	where
+		T: PartialEq,
+	{
+		let len = self.len();
+		self.dedup();
+		len - self.len()
+	}
+}
+
+impl ConstVec<u8> {
+	/// Returns the index of the first occurrence of `needle` in the vector.
+	///
+	/// With the `memchr` feature enabled, this uses the SIMD-accelerated
+	/// search of the [`memchr`](https://docs.rs/memchr) crate. Otherwise it
+	/// falls back to a plain scan.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(b"hello".to_vec());
+	///
+	/// assert_eq!(vec.find_byte(b'l'), Some(2));
+	/// assert_eq!(vec.find_byte(b'z'), None);
+	/// assert_eq!(ConstVec::new(0).find_byte(b'a'), None);
+	/// ```
+	#[inline]
+	pub fn find_byte(&self, needle: u8) -> Option<usize> {
+		#[cfg(feature = "memchr")]
+		{
+			memchr::memchr(needle, self.as_slice())
+		}
+
+		#[cfg(not(feature = "memchr"))]
+		{
+			self.iter().position(|b| *b == needle)
+		}
+	}
+
+	/// Creates a new vector by copying the content of the given [`Bytes`],
+	/// with a capacity equal to its length.
+	///
+	/// [`Bytes`]: bytes::Bytes
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let bytes = bytes::Bytes::from_static(b"hello");
+	/// let vec = ConstVec::from_bytes(&bytes);
+	///
+	/// assert_eq!(vec, b"hello");
+	/// assert_eq!(vec.capacity(), 5);
+	/// assert_eq!(bytes::Bytes::from(vec), bytes);
+	/// ```
+	#[cfg(feature = "bytes")]
+	pub fn from_bytes(b: &bytes::Bytes) -> ConstVec<u8> {
+		Self::from(b.to_vec())
+	}
+
+	/// Writes the length of the vector as a 4-byte big-endian integer,
+	/// followed by its bytes.
+	///
+	/// Returns an [`InvalidInput`](io::ErrorKind::InvalidInput) error if the
+	/// length does not fit in 4 bytes.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(b"abc".to_vec());
+	///
+	/// let mut buffer = Vec::new();
+	/// vec.write_len_prefixed(&mut buffer).unwrap();
+	/// assert_eq!(buffer, b"\0\0\0\x03abc");
+	///
+	/// let read = ConstVec::read_len_prefixed(&mut buffer.as_slice(), 16).unwrap();
+	/// assert_eq!(read, vec);
+	/// assert_eq!(read.capacity(), 3);
+	/// ```
+	pub fn write_len_prefixed<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+		let len = u32::try_from(self.len())
+			.map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "length overflows 4 bytes"))?;
+		w.write_all(&len.to_be_bytes())?;
+		w.write_all(self.as_slice())
+	}
+
+	/// Reads a 4-byte big-endian length followed by as many bytes, as
+	/// written by [`write_len_prefixed`](ConstVec::write_len_prefixed).
+	///
+	/// The returned vector has a capacity equal to the read length. Returns
+	/// an [`InvalidData`](io::ErrorKind::InvalidData) error without reading
+	/// the bytes if the length is greater than `max`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// # use std::io;
+	/// let mut input: &[u8] = b"\0\0\x01\0";
+	///
+	/// let error = ConstVec::read_len_prefixed(&mut input, 255).unwrap_err();
+	/// assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+	/// ```
+	pub fn read_len_prefixed<R: io::Read>(r: &mut R, max: usize) -> io::Result<ConstVec<u8>> {
+		let mut len = [0; 4];
+		r.read_exact(&mut len)?;
+
+		let len = u32::from_be_bytes(len) as usize;
+		if len > max {
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidData,
+				"length exceeds the maximum",
+			));
+		}
+
+		let mut bytes = vec![0; len];
+		r.read_exact(&mut bytes)?;
+		Ok(Self::from(bytes))
+	}
+}
+
+impl<K, V> ConstVec<(K, V)> {
+	/// Returns a reference to the value associated to `key`, pushing
+	/// `(key, default())` first if there is none.
+	///
+	/// This makes small association lists usable through a shared reference.
+	/// Since pushed elements never move, the returned reference stays valid
+	/// while more entries are pushed.
+	///
+	/// # Panics
+	///
+	/// Panics if `key` is not found and the vector is full.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let map = ConstVec::new(2);
+	///
+	/// let a = map.get_or_push("a", || 1);
+	/// let b = map.get_or_push("b", || 2);
+	/// assert_eq!(map.get_or_push("a", || 3), &1);
+	///
+	/// assert_eq!((a, b), (&1, &2));
+	/// assert_eq!(map, [("a", 1), ("b", 2)]);
+	/// ```
+	pub fn get_or_push<F: FnOnce() -> V>(&self, key: K, default: F) -> &V
+	where
+		K: PartialEq,
+	{
+		if let Some((_, value)) = self.iter().find(|(k, _)| *k == key) {
+			return value;
+		}
+
+		let value = default();
+		let index = self.len();
+		self.push((key, value));
+
+		// SAFETY: the element at `index` has just been initialized, and is
+		// never moved through a shared reference.
+		unsafe { &(*self.as_ptr().add(index)).1 }
+	}
+}
+
+impl<T, const N: usize> ConstVec<[T; N]> {
+	/// Returns a view over the elements of every array of the vector, as a
+	/// single slice.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(2);
+	/// vec.push([1u8, 2, 3, 4]);
+	/// vec.push([5, 6, 7, 8]);
+	///
+	/// let flat = vec.as_flattened();
+	/// assert_eq!(flat.len(), 8);
+	/// assert_eq!(flat, [1, 2, 3, 4, 5, 6, 7, 8]);
+	/// ```
+	#[inline]
+	pub fn as_flattened(&self) -> &[T] {
+		self.as_slice().as_flattened()
+	}
 }
 
 impl<T> IntoIterator for ConstVec<T> {
@@ -300,8 +4000,16 @@ impl<T> IntoIterator for ConstVec<T> {
 		let iter = IntoIter {
 			ptr: self.ptr,
 			capacity: self.capacity,
+			layout: self.layout,
+			shrink_threshold: self.shrink_threshold,
+			generation: self.generation.get(),
 			start: self.ptr.as_ptr(),
-			end: unsafe { self.ptr.as_ptr().add(self.len()) },
+			end: if mem::size_of::<T>() == 0 {
+				// Zero-sized elements are counted in bytes past `start`.
+				(self.ptr.as_ptr() as *mut u8).wrapping_add(self.len()) as *mut T
+			} else {
+				unsafe { self.ptr.as_ptr().add(self.len()) }
+			},
 		};
 
 		mem::forget(self);
@@ -318,15 +4026,67 @@ impl<'a, T> IntoIterator for &'a ConstVec<T> {
 	}
 }
 
-impl<T: Clone> Clone for ConstVec<T> {
-	fn clone(&self) -> Self {
-		let result = Self::new(self.capacity);
+/// Iterates over mutable references to the elements of the vector.
+///
+/// # Examples
+///
+/// ```
+/// # use const_vec::ConstVec;
+/// let mut vec = ConstVec::from(vec![1, 2, 3]);
+/// for x in &mut vec {
+///     *x += 1
+/// }
+///
+/// assert_eq!(vec, [2, 3, 4]);
+/// ```
+impl<'a, T> IntoIterator for &'a mut ConstVec<T> {
+	type IntoIter = std::slice::IterMut<'a, T>;
+	type Item = &'a mut T;
 
-		for item in self {
-			result.push(item.clone())
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter_mut()
+	}
+}
+
+/// Extends the vector with the content of an iterator.
+///
+/// Contrarily to [`ConstVec::push`], this requires a mutable reference and
+/// grows the capacity of the vector (see [`ConstVec::grow_to`]) when the
+/// elements do not fit, instead of panicking.
+///
+/// # Examples
+///
+/// ```
+/// # use const_vec::ConstVec;
+/// let mut vec = ConstVec::new(2);
+/// vec.push(1);
+///
+/// vec.extend(vec![2, 3, 4]);
+/// vec.extend((5..=8).filter(|i| i % 2 == 0));
+///
+/// assert_eq!(vec, [1, 2, 3, 4, 6, 8]);
+/// assert!(vec.capacity() >= 6);
+/// ```
+impl<T> Extend<T> for ConstVec<T> {
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		let iter = iter.into_iter();
+		let (lower, _) = iter.size_hint();
+		self.grow_to(self.len().saturating_add(lower));
+
+		for item in iter {
+			if self.len() == self.capacity() {
+				let required = self.len().checked_add(1).expect("capacity overflow");
+				self.grow_to(required.max(self.capacity.saturating_mul(2)))
+			}
+
+			self.push(item)
 		}
+	}
+}
 
-		result
+impl<T: Clone> Clone for ConstVec<T> {
+	fn clone(&self) -> Self {
+		self.try_clone().unwrap_or_else(TryReserveError::handle)
 	}
 }
 
@@ -363,6 +4123,22 @@ impl<T> Deref for ConstVec<T> {
 	}
 }
 
+/// Mutable access to the elements requires a mutable reference to the
+/// vector, so the borrow checker guarantees that no reference returned by
+/// the shared `push` methods (such as [`ConstVec::checked_push`]) is still
+/// alive:
+///
+/// ```compile_fail
+/// # use const_vec::ConstVec;
+/// let mut vec = ConstVec::new(2);
+/// let first = vec.checked_push(1).unwrap();
+///
+/// vec[0] = 2;
+/// assert_eq!(*first, 1);
+/// ```
+///
+/// Only the `unsafe` methods writing through a shared reference, such as
+/// [`ConstVec::set`], can break this guarantee.
 impl<T> DerefMut for ConstVec<T> {
 	#[inline]
 	fn deref_mut(&mut self) -> &mut [T] {
@@ -370,17 +4146,20 @@ impl<T> DerefMut for ConstVec<T> {
 	}
 }
 
+/// A vector is only [`Unpin`] if its elements are, and pinning it pins its
+/// elements. See [`ConstVec::as_pin_slice`].
+impl<T: Unpin> Unpin for ConstVec<T> {}
+
 impl<T> Drop for ConstVec<T> {
 	fn drop(&mut self) {
-		if self.capacity != 0 {
-			unsafe {
-				// use drop for [T]
-				// use a raw slice to refer to the elements of the vector as weakest necessary type;
-				// could avoid questions of validity in certain cases
-				ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), self.len()));
+		unsafe {
+			// use drop for [T]
+			// use a raw slice to refer to the elements of the vector as weakest necessary type;
+			// could avoid questions of validity in certain cases
+			ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), self.len()));
 
-				let layout = Layout::array::<T>(self.capacity).unwrap();
-				alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout);
+			if self.layout.size() != 0 {
+				alloc::dealloc(self.ptr.as_ptr() as *mut u8, self.layout);
 			}
 		}
 	}
@@ -408,53 +4187,721 @@ impl<'a, T: PartialEq<U>, U> PartialEq<&'a [U]> for ConstVec<T> {
 
 impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for ConstVec<T> {
 	#[inline]
-	fn eq(&self, other: &[U; N]) -> bool {
-		*self.as_slice() == *other
+	fn eq(&self, other: &[U; N]) -> bool {
+		*self.as_slice() == *other
+	}
+}
+
+impl<'a, T: PartialEq<U>, U, const N: usize> PartialEq<&'a [U; N]> for ConstVec<T> {
+	#[inline]
+	fn eq(&self, other: &&'a [U; N]) -> bool {
+		*self.as_slice() == **other
+	}
+}
+
+impl<T: PartialEq<U>, U> PartialEq<ConstVec<U>> for ConstVec<T> {
+	#[inline]
+	fn eq(&self, other: &ConstVec<U>) -> bool {
+		*self.as_slice() == *other.as_slice()
+	}
+}
+
+/// Hashes the vector like the equivalent slice: its length is written
+/// first, followed by its elements.
+///
+/// The length prefix prevents collisions between nested vectors that
+/// contain the same elements split differently.
+///
+/// # Examples
+///
+/// ```
+/// # use const_vec::ConstVec;
+/// # use std::collections::hash_map::DefaultHasher;
+/// # use std::hash::{Hash, Hasher};
+/// fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+///     let mut hasher = DefaultHasher::new();
+///     value.hash(&mut hasher);
+///     hasher.finish()
+/// }
+///
+/// let vec = ConstVec::from(vec![1, 2]);
+/// assert_eq!(hash(&vec), hash(&[1, 2][..]));
+/// assert_ne!(hash(&vec), hash(&ConstVec::from(vec![1, 2, 0])));
+///
+/// let a = ConstVec::from(vec![ConstVec::from(vec![1, 2]), ConstVec::from(vec![3])]);
+/// let b = ConstVec::from(vec![ConstVec::from(vec![1]), ConstVec::from(vec![2, 3])]);
+/// assert_ne!(hash(&a), hash(&b));
+/// ```
+impl<T: Hash> Hash for ConstVec<T> {
+	#[inline]
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.as_slice().hash(state)
+	}
+}
+
+impl<T> From<Vec<T>> for ConstVec<T> {
+	fn from(value: Vec<T>) -> Self {
+		let mut value = ManuallyDrop::new(value);
+		let ptr = value.as_mut_ptr();
+		let len = value.len();
+		let capacity = value.capacity();
+		unsafe { Self::from_raw_parts(ptr, len, capacity) }
+	}
+}
+
+impl<T> From<ConstVec<T>> for Vec<T> {
+	fn from(value: ConstVec<T>) -> Self {
+		if value.layout.align() == mem::align_of::<T>() {
+			let (ptr, len, capacity) = value.into_raw_parts();
+			unsafe { Vec::from_raw_parts(ptr, len, capacity) }
+		} else {
+			// `Vec` cannot deallocate an over-aligned buffer.
+			value.into_iter().collect()
+		}
+	}
+}
+
+/// Converts a vector into a boxed slice, dropping any excess capacity.
+///
+/// # Examples
+///
+/// ```
+/// # use const_vec::ConstVec;
+/// let vec = ConstVec::new(10);
+/// vec.push(1);
+/// vec.push(2);
+///
+/// let boxed: Box<[i32]> = vec.into();
+/// assert_eq!(boxed.len(), 2);
+/// assert_eq!(*boxed, [1, 2]);
+/// ```
+impl<T> From<ConstVec<T>> for Box<[T]> {
+	fn from(value: ConstVec<T>) -> Self {
+		value.into_boxed_slice()
+	}
+}
+
+#[cfg(feature = "bytes")]
+impl From<ConstVec<u8>> for bytes::Bytes {
+	fn from(value: ConstVec<u8>) -> Self {
+		Vec::from(value).into()
+	}
+}
+
+/// Collects an iterator of unknown length into a vector whose capacity is
+/// exactly its length.
+///
+/// The elements are first collected into a growable [`Vec`], whose excess
+/// capacity is released before it is converted.
+///
+/// # Examples
+///
+/// ```
+/// # use const_vec::collect_const;
+/// let vec = collect_const((0..10).filter(|i| i % 3 == 0).map(|i| i * 2));
+/// assert_eq!(vec, [0, 6, 12, 18]);
+/// assert_eq!(vec.capacity(), vec.len());
+/// ```
+pub fn collect_const<T, I: IntoIterator<Item = T>>(iter: I) -> ConstVec<T> {
+	let boxed: Box<[T]> = iter.into_iter().collect();
+	Vec::from(boxed).into()
+}
+
+/// Index of an element in a [`ConstVec`], that can detect if the vector has
+/// been modified since the element was pushed.
+///
+/// Handles are created with [`ConstVec::push_handle`] and resolved with
+/// [`ConstVec::get_handle`].
+///
+/// Every method of [`ConstVec`] that removes elements or moves them to
+/// another index, such as [`clear`](ConstVec::clear),
+/// [`pop`](ConstVec::pop) or [`retain_indexed`](ConstVec::retain_indexed),
+/// invalidates all the handles of the vector. Reordering the elements
+/// directly through the mutable slice (for instance with
+/// [`sort`](slice::sort)) is not tracked.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Handle {
+	index: usize,
+	generation: u64,
+}
+
+impl Handle {
+	/// Returns the index of the element in the vector.
+	#[inline]
+	pub fn index(&self) -> usize {
+		self.index
+	}
+}
+
+/// Read-only [`ConstVec`], created by [`ConstVec::into_frozen_handles`].
+///
+/// A frozen vector can neither be modified nor cleared, so every [`Handle`]
+/// issued for it stays valid.
+pub struct FrozenConstVec<T>(ConstVec<T>);
+
+impl<T> FrozenConstVec<T> {
+	#[inline]
+	pub fn as_slice(&self) -> &[T] {
+		self.0.as_slice()
+	}
+
+	/// Returns a reference to the element designated by the given handle.
+	///
+	/// This only returns `None` if the handle was not issued for this vector.
+	#[inline]
+	pub fn get_handle(&self, handle: Handle) -> Option<&T> {
+		self.0.get_handle(handle)
+	}
+}
+
+impl<T> Deref for FrozenConstVec<T> {
+	type Target = [T];
+
+	#[inline]
+	fn deref(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<T> AsRef<[T]> for FrozenConstVec<T> {
+	fn as_ref(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<T: fmt::Debug> fmt::Debug for FrozenConstVec<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&**self, f)
+	}
+}
+
+/// Pair of [`ConstVec`] buffers, where the back buffer is written while the
+/// front buffer is read, until they are flipped with
+/// [`swap`](DoubleBuffer::swap).
+///
+/// # Examples
+///
+/// ```
+/// # use const_vec::DoubleBuffer;
+/// let mut buffer = DoubleBuffer::new(4);
+/// buffer.back_mut().push(1);
+/// buffer.back_mut().push(2);
+/// assert!(buffer.front().is_empty());
+///
+/// buffer.swap();
+/// assert_eq!(buffer.front(), [1, 2]);
+///
+/// buffer.back_mut().push(3);
+/// buffer.swap();
+/// assert_eq!(buffer.front(), [3]);
+/// assert_eq!(buffer.back_mut(), &[1, 2]);
+/// ```
+pub struct DoubleBuffer<T> {
+	front: ConstVec<T>,
+	back: ConstVec<T>,
+}
+
+impl<T> DoubleBuffer<T> {
+	/// Creates a new double buffer, whose two buffers have the given fixed
+	/// capacity.
+	///
+	/// # Panics
+	///
+	/// Panics if the allocated size in bytes exceeds `isize::MAX`.
+	pub fn new(capacity: usize) -> DoubleBuffer<T> {
+		DoubleBuffer {
+			front: ConstVec::new(capacity),
+			back: ConstVec::new(capacity),
+		}
+	}
+
+	/// Returns the content of the front buffer.
+	#[inline]
+	pub fn front(&self) -> &[T] {
+		self.front.as_slice()
+	}
+
+	/// Returns a mutable reference to the back buffer.
+	#[inline]
+	pub fn back_mut(&mut self) -> &mut ConstVec<T> {
+		&mut self.back
+	}
+
+	/// Flips the front and back buffers, in O(1).
+	#[inline]
+	pub fn swap(&mut self) {
+		self.front.swap_contents(&mut self.back)
+	}
+}
+
+impl<T: fmt::Debug> fmt::Debug for DoubleBuffer<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("DoubleBuffer")
+			.field("front", &self.front)
+			.field("back", &self.back)
+			.finish()
+	}
+}
+
+/// Copy-on-write [`ConstVec`].
+///
+/// Cloning a `CowConstVec` only clones a reference-counted pointer to the
+/// underlying vector. The buffer itself is cloned, with the same capacity,
+/// the first time a shared vector is modified.
+///
+/// # Examples
+///
+/// ```
+/// # use const_vec::CowConstVec;
+/// let mut a = CowConstVec::new(4);
+/// a.push(1);
+///
+/// let b = a.clone();
+/// assert_eq!(a.as_ptr(), b.as_ptr());
+/// assert!(a.is_shared());
+///
+/// a.push(2);
+/// assert!(!a.is_shared());
+/// assert_ne!(a.as_ptr(), b.as_ptr());
+/// assert_eq!(a.as_slice(), [1, 2]);
+/// assert_eq!(b.as_slice(), [1]);
+/// assert_eq!(a.capacity(), 4);
+/// ```
+pub struct CowConstVec<T>(Rc<ConstVec<T>>);
+
+impl<T> CowConstVec<T> {
+	/// Creates a new copy-on-write vector with the given fixed capacity.
+	///
+	/// # Panics
+	///
+	/// Panics if the allocated size in bytes exceeds `isize::MAX`.
+	pub fn new(capacity: usize) -> CowConstVec<T> {
+		CowConstVec(Rc::new(ConstVec::new(capacity)))
+	}
+
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.0.capacity()
+	}
+
+	#[inline]
+	pub fn as_slice(&self) -> &[T] {
+		self.0.as_slice()
+	}
+
+	/// Returns `true` if the underlying vector is shared with another
+	/// `CowConstVec`, meaning that the next modification clones it.
+	#[inline]
+	pub fn is_shared(&self) -> bool {
+		Rc::strong_count(&self.0) > 1
+	}
+
+	/// Returns a mutable reference to the underlying vector, cloning it
+	/// first if it is shared.
+	#[inline]
+	pub fn make_mut(&mut self) -> &mut ConstVec<T>
+	where
+		T: Clone,
+	{
+		Rc::make_mut(&mut self.0)
+	}
+
+	/// Appends an element to the vector, cloning it first if it is shared.
+	///
+	/// # Panics
+	///
+	/// Panics if the vector is full.
+	#[inline]
+	pub fn push(&mut self, value: T)
+	where
+		T: Clone,
+	{
+		self.make_mut().push(value)
+	}
+}
+
+impl<T> Clone for CowConstVec<T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		CowConstVec(self.0.clone())
+	}
+}
+
+impl<T> Deref for CowConstVec<T> {
+	type Target = [T];
+
+	#[inline]
+	fn deref(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<T> From<ConstVec<T>> for CowConstVec<T> {
+	#[inline]
+	fn from(value: ConstVec<T>) -> Self {
+		CowConstVec(Rc::new(value))
+	}
+}
+
+impl<T: fmt::Debug> fmt::Debug for CowConstVec<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(self.as_slice(), f)
+	}
+}
+
+/// Returns a dangling pointer aligned on `layout`, for zero-sized
+/// allocations.
+#[inline]
+fn dangling<T>(layout: Layout) -> NonNull<T> {
+	// SAFETY: alignments are never zero.
+	unsafe { NonNull::new_unchecked(layout.align() as *mut T) }
+}
+
+/// Bulk insertion helper for a [`ConstVec`], created by
+/// [`ConstVec::builder`].
+///
+/// The capacity of the vector is checked once when creating the builder, so
+/// that [`push`](Builder::push) only has to count the pushed elements.
+pub struct Builder<'a, T> {
+	vec: &'a mut ConstVec<T>,
+	remaining: usize,
+}
+
+impl<'a, T> Builder<'a, T> {
+	/// Returns the number of elements that can still be pushed through this
+	/// builder.
+	#[inline]
+	pub fn remaining(&self) -> usize {
+		self.remaining
+	}
+
+	/// Appends an element to the back of the vector.
+	///
+	/// # Panics
+	///
+	/// Panics if more elements are pushed than the count given to
+	/// [`ConstVec::builder`].
+	#[inline]
+	pub fn push(&mut self, value: T) {
+		if self.remaining == 0 {
+			panic!("builder count exceeded")
+		}
+
+		// SAFETY: the capacity for `remaining` more elements was checked when
+		// creating the builder, and nothing else can push in the meantime.
+		unsafe {
+			let len = self.vec.len();
+			ptr::write(self.vec.as_mut_ptr().add(len), value);
+			self.vec.len.set(len + 1);
+		}
+
+		self.remaining -= 1;
+	}
+}
+
+/// Iterator which uses a closure to determine if an element should be
+/// removed from a [`ConstVec`].
+///
+/// This struct is created by [`ConstVec::extract_if`].
+pub struct ExtractIf<'a, T, F: FnMut(&mut T) -> bool> {
+	vec: &'a mut ConstVec<T>,
+
+	/// Index of the next element to inspect.
+	idx: usize,
+
+	/// Number of elements removed so far.
+	del: usize,
+
+	/// Length of the vector before extraction.
+	old_len: usize,
+
+	pred: F,
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'a, T, F> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<T> {
+		// SAFETY: elements in `idx..old_len` are initialized and were not
+		// moved yet, and the holes left by the removed elements are all
+		// before `idx`.
+		unsafe {
+			let base = self.vec.as_mut_ptr();
+			while self.idx < self.old_len {
+				let cur = base.add(self.idx);
+				let drained = (self.pred)(&mut *cur);
+
+				// Update the index *after* the predicate is called. If the index
+				// is updated prior and the predicate panics, the element at this
+				// index would be leaked.
+				self.idx += 1;
+
+				if drained {
+					self.del += 1;
+					return Some(ptr::read(cur));
+				} else if self.del > 0 {
+					ptr::copy_nonoverlapping(cur, cur.sub(self.del), 1);
+				}
+			}
+
+			None
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.old_len - self.idx))
+	}
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool> Drop for ExtractIf<'a, T, F> {
+	fn drop(&mut self) {
+		// SAFETY: the elements that were not inspected are shifted over the
+		// holes left by the removed ones.
+		unsafe {
+			if self.idx < self.old_len && self.del > 0 {
+				let src = self.vec.as_mut_ptr().add(self.idx);
+				ptr::copy(src, src.sub(self.del), self.old_len - self.idx);
+			}
+
+			self.vec.len.set(self.old_len - self.del);
+		}
+	}
+}
+
+/// Allocation summary of a [`ConstVec`], returned by
+/// [`ConstVec::alloc_info`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AllocInfo {
+	/// Number of elements the buffer can hold.
+	pub capacity: usize,
+
+	/// Number of initialized elements.
+	pub len: usize,
+
+	/// Size of one element, in bytes.
+	pub element_size: usize,
+
+	/// Alignment of the buffer, in bytes.
+	pub alignment: usize,
+
+	/// Size of the allocated buffer, in bytes.
+	///
+	/// This is zero when nothing was allocated, for instance for zero-sized
+	/// element types or a zero capacity.
+	pub bytes_allocated: usize,
+}
+
+/// Error returned when a vector does not have enough remaining capacity for
+/// an operation.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CapacityError {
+	requested: usize,
+	remaining: usize,
+}
+
+impl CapacityError {
+	/// Returns the number of elements the operation required room for.
+	#[inline]
+	pub fn requested(&self) -> usize {
+		self.requested
 	}
-}
 
-impl<'a, T: PartialEq<U>, U, const N: usize> PartialEq<&'a [U; N]> for ConstVec<T> {
+	/// Returns the remaining capacity of the vector.
 	#[inline]
-	fn eq(&self, other: &&'a [U; N]) -> bool {
-		*self.as_slice() == **other
+	pub fn remaining(&self) -> usize {
+		self.remaining
 	}
 }
 
-impl<T: PartialEq<U>, U> PartialEq<ConstVec<U>> for ConstVec<T> {
-	#[inline]
-	fn eq(&self, other: &ConstVec<U>) -> bool {
-		*self.as_slice() == *other.as_slice()
+impl fmt::Display for CapacityError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"not enough capacity (requested {}, remaining {})",
+			self.requested, self.remaining
+		)
 	}
 }
 
-impl<T> From<Vec<T>> for ConstVec<T> {
-	fn from(value: Vec<T>) -> Self {
-		let mut value = ManuallyDrop::new(value);
-		let ptr = value.as_mut_ptr();
-		let len = value.len();
-		let capacity = value.capacity();
-		unsafe { Self::from_raw_parts(ptr, len, capacity) }
+impl std::error::Error for CapacityError {}
+
+/// Error returned by the fallible allocation methods of [`ConstVec`], such as
+/// [`ConstVec::try_new`].
+///
+/// # Examples
+///
+/// ```
+/// # use const_vec::{ConstVec, TryReserveError};
+/// # use std::alloc::{GlobalAlloc, Layout, System};
+/// # use std::sync::atomic::{AtomicBool, Ordering};
+/// #
+/// # static FAIL: AtomicBool = AtomicBool::new(false);
+/// #
+/// # struct FailingAlloc;
+/// #
+/// # unsafe impl GlobalAlloc for FailingAlloc {
+/// #     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+/// #         if FAIL.load(Ordering::SeqCst) {
+/// #             std::ptr::null_mut()
+/// #         } else {
+/// #             System.alloc(layout)
+/// #         }
+/// #     }
+/// #
+/// #     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+/// #         System.dealloc(ptr, layout)
+/// #     }
+/// #
+/// #     unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+/// #         if FAIL.load(Ordering::SeqCst) {
+/// #             std::ptr::null_mut()
+/// #         } else {
+/// #             System.realloc(ptr, layout, new_size)
+/// #         }
+/// #     }
+/// # }
+/// #
+/// # #[global_allocator]
+/// # static ALLOC: FailingAlloc = FailingAlloc;
+/// #
+/// # fn main() {
+/// let mut vec = ConstVec::<u32>::new(4);
+/// vec.push(1);
+///
+/// // Make every allocation fail from now on.
+/// FAIL.store(true, Ordering::SeqCst);
+///
+/// assert!(matches!(
+///     ConstVec::<u32>::try_new(4),
+///     Err(TryReserveError::AllocError { .. })
+/// ));
+/// assert!(matches!(
+///     vec.try_clone(),
+///     Err(TryReserveError::AllocError { .. })
+/// ));
+/// assert!(matches!(
+///     vec.try_grow_to(8),
+///     Err(TryReserveError::AllocError { .. })
+/// ));
+/// assert!(matches!(
+///     vec.try_shrink_to_fit(),
+///     Err(TryReserveError::AllocError { .. })
+/// ));
+///
+/// FAIL.store(false, Ordering::SeqCst);
+/// assert_eq!(vec, [1]);
+/// assert_eq!(vec.capacity(), 4);
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TryReserveError {
+	/// The computed capacity exceeded the maximum allocation size.
+	CapacityOverflow,
+
+	/// The memory allocator returned an error.
+	AllocError {
+		/// The layout of the allocation request that failed.
+		layout: Layout,
+	},
+}
+
+impl TryReserveError {
+	/// Reports the error the way infallible methods do: by panicking on
+	/// capacity overflow, or by calling [`alloc::handle_alloc_error`].
+	fn handle<U>(self) -> U {
+		match self {
+			Self::CapacityOverflow => panic!("capacity overflow"),
+			Self::AllocError { layout } => alloc::handle_alloc_error(layout),
+		}
 	}
 }
 
-impl<T> From<ConstVec<T>> for Vec<T> {
-	fn from(value: ConstVec<T>) -> Self {
-		let (ptr, len, capacity) = value.into_raw_parts();
-		unsafe { Vec::from_raw_parts(ptr, len, capacity) }
+impl fmt::Display for TryReserveError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("memory allocation failed")?;
+		match self {
+			Self::CapacityOverflow => {
+				f.write_str(" because the computed capacity exceeded the collection's maximum")
+			}
+			Self::AllocError { .. } => {
+				f.write_str(" because the memory allocator returned an error")
+			}
+		}
 	}
 }
 
+impl std::error::Error for TryReserveError {}
+
 pub struct IntoIter<T> {
 	ptr: NonNull<T>,
 	capacity: usize,
+	layout: Layout,
+
+	/// Automatic shrinking threshold of the original vector, restored by
+	/// [`IntoIter::into_const_vec`].
+	shrink_threshold: usize,
+
+	/// [`Handle`] generation of the original vector, bumped whenever an
+	/// element is consumed, and restored by [`IntoIter::into_const_vec`].
+	generation: u64,
+
 	start: *mut T,
+
+	/// End of the remaining elements.
+	///
+	/// For zero-sized `T`, `start` never moves, and this is instead `start`
+	/// plus the number of remaining elements in bytes.
 	end: *mut T,
 }
 
 impl<T> IntoIter<T> {
 	#[inline]
 	pub fn len(&self) -> usize {
-		(self.end as usize - self.start as usize) / mem::size_of::<T>()
+		match mem::size_of::<T>() {
+			0 => (self.end as usize).wrapping_sub(self.start as usize),
+			size => (self.end as usize - self.start as usize) / size,
+		}
+	}
+
+	/// Removes `n` elements from the front of the remaining range, and
+	/// returns a pointer to the first one.
+	///
+	/// `n` must not exceed the number of remaining elements.
+	#[inline]
+	unsafe fn take_front(&mut self, n: usize) -> *mut T {
+		debug_assert!(n <= self.len());
+		let first = self.start;
+
+		if n > 0 {
+			self.generation = self.generation.wrapping_add(1)
+		}
+
+		if mem::size_of::<T>() == 0 {
+			self.end = (self.end as *mut u8).wrapping_sub(n) as *mut T
+		} else {
+			self.start = self.start.add(n)
+		}
+
+		first
+	}
+
+	/// Removes the last remaining element, and returns a pointer to it.
+	///
+	/// There must be at least one remaining element.
+	#[inline]
+	unsafe fn take_back(&mut self) -> *mut T {
+		debug_assert!(self.start != self.end);
+		self.generation = self.generation.wrapping_add(1);
+
+		if mem::size_of::<T>() == 0 {
+			self.end = (self.end as *mut u8).wrapping_sub(1) as *mut T;
+			// `end` may not be aligned, but any aligned pointer is valid
+			// for a zero-sized read.
+			self.start
+		} else {
+			self.end = self.end.offset(-1);
+			self.end
+		}
 	}
 
 	#[inline]
@@ -481,6 +4928,78 @@ impl<T> IntoIter<T> {
 	pub fn as_mut_slice(&mut self) -> &mut [T] {
 		unsafe { std::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len()) }
 	}
+
+	/// Returns a reference to the next element, without consuming it.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2]);
+	/// let mut iter = vec.into_iter();
+	///
+	/// assert_eq!(iter.peek(), Some(&1));
+	/// assert_eq!(iter.next(), Some(1));
+	/// assert_eq!(iter.peek(), Some(&2));
+	/// assert_eq!(iter.next(), Some(2));
+	/// assert_eq!(iter.peek(), None);
+	/// ```
+	#[inline]
+	pub fn peek(&self) -> Option<&T> {
+		self.as_slice().first()
+	}
+
+	/// Returns the capacity of the vector this iterator was created from.
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.capacity
+	}
+
+	/// Converts the iterator back into a vector holding the remaining
+	/// elements, reusing the original buffer and capacity.
+	///
+	/// The remaining elements are shifted to the front of the buffer. If any
+	/// element was consumed, the [`Handle`]s issued by the original vector
+	/// are invalidated.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(8);
+	/// for i in 0..5 {
+	///     vec.push(i);
+	/// }
+	///
+	/// let mut iter = vec.into_iter();
+	/// assert_eq!(iter.capacity(), 8);
+	/// assert_eq!(iter.next(), Some(0));
+	/// assert_eq!(iter.next_back(), Some(4));
+	///
+	/// let vec = iter.into_const_vec();
+	/// assert_eq!(vec, [1, 2, 3]);
+	/// assert_eq!(vec.capacity(), 8);
+	///
+	/// vec.push(5);
+	/// assert_eq!(vec, [1, 2, 3, 5]);
+	/// ```
+	pub fn into_const_vec(self) -> ConstVec<T> {
+		let me = ManuallyDrop::new(self);
+		let len = me.len();
+
+		// SAFETY: the remaining elements are moved to the front of the
+		// buffer, which is then owned by the returned vector.
+		unsafe { ptr::copy(me.start, me.ptr.as_ptr(), len) };
+
+		ConstVec {
+			ptr: me.ptr,
+			capacity: me.capacity,
+			layout: me.layout,
+			len: Cell::new(len),
+			generation: Cell::new(me.generation),
+			shrink_threshold: me.shrink_threshold,
+		}
+	}
 }
 
 impl<T> Iterator for IntoIter<T> {
@@ -495,42 +5014,535 @@ impl<T> Iterator for IntoIter<T> {
 		if self.start == self.end {
 			None
 		} else {
+			unsafe { Some(ptr::read(self.take_front(1))) }
+		}
+	}
+
+	/// Returns the `n`th remaining element, dropping all the skipped elements
+	/// at once.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![0, 1, 2, 3, 4]);
+	///
+	/// let mut iter = vec.into_iter();
+	/// assert_eq!(iter.nth(2), Some(2));
+	/// assert_eq!(iter.nth(5), None);
+	/// ```
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		let step = n.min(self.len());
+
+		// SAFETY: the skipped elements are removed from the remaining range
+		// before being dropped so that a panicking `Drop` impl cannot cause a
+		// double drop.
+		unsafe {
+			let skipped = ptr::slice_from_raw_parts_mut(self.take_front(step), step);
+			ptr::drop_in_place(skipped);
+		}
+
+		self.next()
+	}
+
+	/// Consumes the iterator, dropping the remaining elements at once, and
+	/// returns how many there were.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![0, 1, 2, 3, 4]);
+	///
+	/// let mut iter = vec.into_iter();
+	/// iter.next();
+	/// assert_eq!(iter.count(), 4);
+	/// ```
+	#[inline]
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	/// Consumes the iterator, returning the last element and dropping the
+	/// other remaining elements at once.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![0, 1, 2, 3, 4]);
+	/// assert_eq!(vec.into_iter().last(), Some(4));
+	///
+	/// assert_eq!(ConstVec::<u32>::new(1).into_iter().last(), None);
+	/// ```
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		// The other elements are dropped in bulk by the `Drop` impl.
+		self.next_back()
+	}
+
+	/// Folds every remaining element into an accumulator, iterating directly
+	/// over the remaining range instead of going through [`next`].
+	///
+	/// If `f` panics, the elements that were not yet passed to `f` are
+	/// dropped exactly once when the iterator is unwound.
+	///
+	/// The unstable `Try` trait in its signature prevents `try_fold` from
+	/// being overridden the same way on stable Rust.
+	///
+	/// [`next`]: Iterator::next
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::from(vec![1, 2, 3, 4]);
+	/// assert_eq!(vec.into_iter().fold(0, |sum, item| sum + item), 10);
+	/// ```
+	fn fold<B, F>(mut self, init: B, mut f: F) -> B
+	where
+		F: FnMut(B, Self::Item) -> B,
+	{
+		let mut acc = init;
+
+		for _ in 0..self.len() {
+			// SAFETY: there is at least one remaining element here, and it is
+			// removed from the remaining range before calling `f` so that the
+			// moved item is never dropped again if `f` panics.
 			unsafe {
-				let result = ptr::read(self.start);
-				self.start = self.start.offset(1);
-				Some(result)
+				let item = ptr::read(self.take_front(1));
+				acc = f(acc, item);
 			}
 		}
+
+		acc
 	}
 }
 
 impl<T> ExactSizeIterator for IntoIter<T> {}
 
 impl<T> DoubleEndedIterator for IntoIter<T> {
+	/// Removes and returns the last remaining element.
+	///
+	/// Zero-sized elements are counted rather than addressed, so they are
+	/// yielded the right number of times from both ends.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use const_vec::ConstVec;
+	/// let vec = ConstVec::new(3);
+	/// for _ in 0..3 {
+	///     vec.push(());
+	/// }
+	///
+	/// assert_eq!(vec.into_iter().rev().count(), 3);
+	/// ```
 	fn next_back(&mut self) -> Option<Self::Item> {
 		if self.start == self.end {
 			None
 		} else {
-			unsafe {
-				self.end = self.end.offset(-1);
-				Some(ptr::read(self.end))
-			}
+			unsafe { Some(ptr::read(self.take_back())) }
 		}
 	}
 }
 
+/// Shows the remaining elements along with the capacity of the original
+/// vector.
+///
+/// # Examples
+///
+/// ```
+/// # use const_vec::ConstVec;
+/// let vec = ConstVec::new(4);
+/// vec.push(1);
+/// vec.push(2);
+///
+/// let mut iter = vec.into_iter();
+/// iter.next();
+/// assert_eq!(format!("{:?}", iter), "IntoIter { remaining: [2], capacity: 4 }");
+/// ```
+impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("IntoIter")
+			.field("remaining", &self.as_slice())
+			.field("capacity", &self.capacity)
+			.finish()
+	}
+}
+
 impl<T> Drop for IntoIter<T> {
 	fn drop(&mut self) {
-		if self.capacity != 0 {
-			unsafe {
-				// use drop for [T]
-				// use a raw slice to refer to the elements of the vector as weakest necessary type;
-				// could avoid questions of validity in certain cases
-				ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), self.len()));
+		unsafe {
+			// use drop for [T]
+			// use a raw slice to refer to the elements of the vector as weakest necessary type;
+			// could avoid questions of validity in certain cases
+			ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), self.len()));
+
+			if self.layout.size() != 0 {
+				alloc::dealloc(self.ptr.as_ptr() as *mut u8, self.layout);
+			}
+		}
+	}
+}
+
+/// Iterator over `N` elements of a [`ConstVec`] at a time, as arrays.
+///
+/// This struct is created by [`ConstVec::into_array_chunks`].
+pub struct IntoArrayChunks<T, const N: usize> {
+	iter: IntoIter<T>,
+}
+
+impl<T, const N: usize> IntoArrayChunks<T, N> {
+	/// Returns the elements that do not fill a whole chunk, and will not be
+	/// yielded.
+	#[inline]
+	pub fn remainder(&self) -> &[T] {
+		let slice = self.iter.as_slice();
+		&slice[(slice.len() - slice.len() % N)..]
+	}
+
+	/// Consumes the iterator into an iterator over the elements that were not
+	/// yielded yet.
+	///
+	/// Once every chunk has been yielded, those are the elements returned by
+	/// [`remainder`](IntoArrayChunks::remainder).
+	#[inline]
+	pub fn into_remainder(self) -> IntoIter<T> {
+		self.iter
+	}
+}
+
+impl<T, const N: usize> Iterator for IntoArrayChunks<T, N> {
+	type Item = [T; N];
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.iter.len() / N;
+		(len, Some(len))
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.iter.len() < N {
+			None
+		} else {
+			// SAFETY: there are at least `N` remaining elements, which are
+			// moved out of the inner iterator.
+			unsafe { Some(ptr::read(self.iter.take_front(N) as *const [T; N])) }
+		}
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoArrayChunks<T, N> {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::panic::{self, AssertUnwindSafe};
+
+	thread_local!(static DROPS: Cell<usize> = const { Cell::new(0) });
+
+	/// Value counting how many times it is dropped on the current thread.
+	struct Counted<V = u32>(V);
+
+	impl<V> Drop for Counted<V> {
+		fn drop(&mut self) {
+			DROPS.with(|drops| drops.set(drops.get() + 1))
+		}
+	}
+
+	fn reset_drops() {
+		DROPS.with(|drops| drops.set(0))
+	}
+
+	fn drops() -> usize {
+		DROPS.with(Cell::get)
+	}
+
+	/// Resets the drop counter, and returns a full vector of `n` counted
+	/// values from `0` to `n - 1`.
+	fn counted(n: u32) -> ConstVec<Counted> {
+		reset_drops();
+		let vec = ConstVec::new(n as usize);
+		for i in 0..n {
+			vec.push(Counted(i))
+		}
+
+		vec
+	}
+
+	fn values(vec: &[Counted]) -> Vec<u32> {
+		vec.iter().map(|c| c.0).collect()
+	}
 
-				let layout = Layout::array::<T>(self.capacity).unwrap();
-				alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout);
+	#[test]
+	#[should_panic(expected = "capacity 0")]
+	fn push_zero_capacity() {
+		ConstVec::new(0).push(1)
+	}
+
+	#[test]
+	#[should_panic(expected = "not enough capacity")]
+	fn push_full() {
+		let vec = ConstVec::new(1);
+		vec.push(1);
+		vec.push(2);
+	}
+
+	#[test]
+	fn auto_shrink_is_kept() {
+		fn check(vec: ConstVec<u32>) {
+			let mut vec = vec;
+			while vec.len() > 9 {
+				vec.pop();
+			}
+
+			assert_eq!(vec.capacity(), 10);
+		}
+
+		let vec = ConstVec::new_auto_shrink(100, 10);
+		for i in 0..20 {
+			vec.push(i);
+		}
+
+		check(vec.clone());
+		check(vec.clone().map_into(|i| i + 1));
+		check(vec.clone().try_map_into::<_, (), _>(Ok).unwrap());
+		check(vec.clone().map_in_place(|i| i + 1));
+
+		let mut iter = vec.into_iter();
+		iter.next();
+		check(iter.into_const_vec());
+	}
+
+	#[test]
+	fn map_into_panic() {
+		let vec = counted(5);
+		let result = panic::catch_unwind(AssertUnwindSafe(|| {
+			vec.map_into(|item| {
+				if item.0 == 3 {
+					panic!("mapping failed")
+				}
+
+				Counted(item.0 * 10)
+			})
+		}));
+
+		assert!(result.is_err());
+		// 5 original elements, plus the 3 mapped ones.
+		assert_eq!(drops(), 8);
+	}
+
+	#[test]
+	fn map_in_place_panic() {
+		let vec = counted(5);
+		let result = panic::catch_unwind(AssertUnwindSafe(|| {
+			vec.map_in_place(|item| {
+				if item.0 == 3 {
+					panic!("mapping failed")
+				}
+
+				Counted(item.0 * 10)
+			})
+		}));
+
+		assert!(result.is_err());
+		assert_eq!(drops(), 8);
+	}
+
+	#[test]
+	fn try_map_into_error() {
+		let vec = counted(5);
+		let result = vec.try_map_into(|item| {
+			if item.0 == 3 {
+				Err(item.0)
+			} else {
+				Ok(Counted(item.0 * 10))
 			}
+		});
+
+		assert_eq!(result.err(), Some(3));
+		assert_eq!(drops(), 8);
+	}
+
+	#[test]
+	fn retain_indexed_drops() {
+		let mut vec = counted(5);
+		vec.retain_indexed(|i, _| i % 2 == 0);
+		assert_eq!(drops(), 2);
+		assert_eq!(values(&vec), [0, 2, 4]);
+	}
+
+	#[test]
+	fn take_drops_nothing() {
+		reset_drops();
+		let mut vec = ConstVec::new(2);
+		vec.push(Some(Counted(1)));
+		vec.push(Some(Counted(2)));
+
+		let taken = vec.take(0);
+		assert_eq!(taken.as_ref().map(|c| c.0), Some(1));
+		assert!(vec[0].is_none());
+		assert_eq!(drops(), 0);
+
+		drop(taken);
+		drop(vec);
+		assert_eq!(drops(), 2);
+	}
+
+	#[test]
+	fn split_into_halves_drops() {
+		let vec = counted(5);
+		let (a, b) = vec.split_into_halves();
+		assert_eq!(drops(), 0);
+
+		drop(a);
+		drop(b);
+		assert_eq!(drops(), 5);
+	}
+
+	#[test]
+	fn keep_first_drops() {
+		let mut vec = counted(5);
+		vec.keep_first(2);
+		assert_eq!(drops(), 3);
+		assert_eq!(values(&vec), [0, 1]);
+	}
+
+	#[test]
+	fn keep_last_drops() {
+		let mut vec = counted(5);
+		vec.keep_last(2);
+		assert_eq!(drops(), 3);
+		assert_eq!(values(&vec), [3, 4]);
+	}
+
+	#[test]
+	fn into_iter_nth_drops() {
+		let mut iter = counted(5).into_iter();
+		let third = iter.nth(2).unwrap();
+		assert_eq!(third.0, 2);
+		assert_eq!(drops(), 2);
+
+		assert!(iter.nth(5).is_none());
+		assert_eq!(drops(), 4);
+	}
+
+	#[test]
+	fn into_iter_count_drops() {
+		let mut iter = counted(5).into_iter();
+		iter.next();
+		assert_eq!(drops(), 1);
+		assert_eq!(iter.count(), 4);
+		assert_eq!(drops(), 5);
+	}
+
+	#[test]
+	fn into_iter_last_drops() {
+		let last = counted(5).into_iter().last().unwrap();
+		assert_eq!(last.0, 4);
+		assert_eq!(drops(), 4);
+
+		drop(last);
+		assert_eq!(drops(), 5);
+	}
+
+	#[test]
+	fn into_iter_fold_panic() {
+		let vec = counted(5);
+		let result = panic::catch_unwind(AssertUnwindSafe(|| {
+			vec.into_iter().fold(0, |sum, item| {
+				if item.0 == 2 {
+					panic!("short-circuit")
+				}
+
+				sum + item.0
+			})
+		}));
+
+		assert!(result.is_err());
+		assert_eq!(drops(), 5);
+	}
+
+	#[test]
+	fn into_iter_zero_sized_wrapping() {
+		let vec = ConstVec::from(vec![()]).repeat_const(usize::MAX);
+		let mut iter = vec.into_iter();
+		assert_eq!(iter.len(), usize::MAX);
+		assert_eq!(iter.next(), Some(()));
+		assert_eq!(iter.next_back(), Some(()));
+		assert_eq!(iter.len(), usize::MAX - 2);
+		drop(iter);
+	}
+
+	#[test]
+	fn into_iter_zero_sized_drops() {
+		reset_drops();
+		let vec = ConstVec::new(4);
+		for _ in 0..4 {
+			vec.push(Counted(()));
 		}
+
+		let mut iter = vec.into_iter();
+		assert_eq!(iter.len(), 4);
+		assert!(iter.next_back().is_some());
+		assert!(iter.next().is_some());
+		assert_eq!(iter.len(), 2);
+		assert_eq!(drops(), 2);
+
+		drop(iter);
+		assert_eq!(drops(), 4);
+	}
+
+	#[test]
+	fn handles_invalidated_by_removal() {
+		let mut vec = ConstVec::new(4);
+		let a = vec.push_handle(1);
+		vec.push(2);
+		vec.keep_first(1);
+		vec.push(3);
+		assert_eq!(vec.get_handle(a), None);
+
+		let b = vec.push_handle(4);
+		assert_eq!(vec.pop(), Some(4));
+		vec.push(5);
+		assert_eq!(vec.get_handle(b), None);
+
+		let c = vec.push_handle(6);
+		vec.drain_tail_into(0, &mut Vec::new());
+		vec.push(7);
+		assert_eq!(vec.get_handle(c), None);
+
+		let d = vec.push_handle(8);
+		vec.binary_insert(0);
+		assert_eq!(vec.get_handle(d), None);
+
+		let e = vec.push_handle(9);
+		vec.keep_first(4);
+		vec.drain_tail_into(4, &mut Vec::new());
+		assert_eq!(vec.get_handle(e), Some(&9));
+	}
+
+	#[test]
+	fn into_const_vec_handles() {
+		let vec = ConstVec::new(4);
+		let a = vec.push_handle(1);
+		let b = vec.push_handle(2);
+
+		let vec = vec.into_iter().into_const_vec();
+		assert_eq!(vec.get_handle(a), Some(&1));
+		assert_eq!(vec.get_handle(b), Some(&2));
+
+		let mut iter = vec.into_iter();
+		assert_eq!(iter.next(), Some(1));
+		let vec = iter.into_const_vec();
+		assert_eq!(vec.get_handle(a), None);
+		assert_eq!(vec.get_handle(b), None);
+
+		let c = vec.push_handle(3);
+		let mut iter = vec.into_iter();
+		assert_eq!(iter.next_back(), Some(3));
+		let vec = iter.into_const_vec();
+		vec.push(4);
+		assert_eq!(vec.get_handle(c), None);
 	}
 }